@@ -1,9 +1,18 @@
 use {
   quote::ToTokens,
   std::{collections::BTreeMap, env, fs, path::PathBuf},
-  syn::{FnArg, Item, ItemFn, Pat, PatType, ReturnType, Type},
+  syn::{FnArg, Item, ItemFn, Pat, PatType, ReturnType, Type, ext::IdentExt},
 };
 
+const CONTEXT: &[(&str, &str)] = &[
+  ("app", "&mut super::App"),
+  ("event_loop", "&ActiveEventLoop"),
+  ("history", "&mut super::History"),
+  ("rng", "&mut SmallRng"),
+  ("state", "&mut super::State"),
+  ("tap", "&mut super::Tap"),
+];
+
 const PATH: &str = "src/commands.rs";
 
 fn main() {
@@ -31,8 +40,10 @@ fn main() {
 
   let mut commands = BTreeMap::new();
 
+  let mut wrappers = Vec::new();
+
   for function in functions {
-    let name = function.sig.ident.to_string();
+    let name = function.sig.ident.unraw().to_string();
 
     let inputs = function
       .sig
@@ -87,20 +98,35 @@ fn main() {
 
     let inputs = inputs.iter().map(String::as_str).collect::<Vec<&str>>();
 
-    let variant = match (inputs.as_slice(), fallible) {
-      (["app", "event_loop"], false) => "AppEventLoop",
-      (["app"], false) => "App",
-      (["app"], true) => "AppFallible",
-      (["history", "state"], false) => "HistoryState",
-      (["history"], false) => "History",
-      (["rng", "state"], false) => "RngState",
-      (["state"], false) => "State",
+    let split = inputs
+      .iter()
+      .position(|input| !CONTEXT.iter().any(|(context, _ty)| context == input))
+      .unwrap_or(inputs.len());
+
+    let (context, arguments) = inputs.split_at(split);
+
+    let variant = match (context, fallible, arguments.is_empty()) {
+      (["app", "event_loop"], false, true) => "AppEventLoop",
+      (["app"], false, true) => "App",
+      (["app"], true, true) => "AppFallible",
+      (["history", "state"], false, true) => "HistoryState",
+      (["history"], false, true) => "History",
+      (["rng", "state"], false, true) => "RngState",
+      (["rng", "state"], false, false) => "RngStateArguments",
+      (["state"], false, true) => "State",
+      (["state"], false, false) => "StateArguments",
+      (["tap"], false, true) => "Tap",
+      (["tap"], false, false) => "TapArguments",
       _ => panic!(
         "unsupported combination of inputs and fallibility: ({}, {fallible})",
         inputs.join(" ")
       ),
     };
 
+    if !arguments.is_empty() {
+      wrappers.push(wrapper(&name, context, arguments));
+    }
+
     commands.insert(name, variant);
   }
 
@@ -112,7 +138,7 @@ fn main() {
 
   for (name, variant) in &commands {
     lines.push(format!(
-      "pub(crate) const {}: CommandEntry = CommandEntry::new(\"{}\", {variant}({name}));",
+      "pub(crate) const {}: CommandEntry = CommandEntry::new(\"{}\", {variant}(r#{name}));",
       name.to_uppercase(),
       name.replace('_', "-"),
     ));
@@ -126,7 +152,14 @@ fn main() {
     lines.push(format!("  {},", name.to_uppercase()));
   }
 
-  lines.push("];\n".into());
+  lines.push("];".into());
+
+  for wrapper in wrappers {
+    lines.push(String::new());
+    lines.extend(wrapper);
+  }
+
+  lines.push(String::new());
 
   fs::write(
     PathBuf::from(env::var("OUT_DIR").unwrap()).join("generated.rs"),
@@ -134,3 +167,50 @@ fn main() {
   )
   .unwrap();
 }
+
+fn wrapper(name: &str, context: &[&str], arguments: &[&str]) -> Vec<String> {
+  let mut lines = Vec::new();
+
+  let mut parameters = context
+    .iter()
+    .map(|context| {
+      let (_context, ty) = CONTEXT.iter().find(|(name, _ty)| name == context).unwrap();
+      format!("{context}: {ty}")
+    })
+    .collect::<Vec<String>>();
+
+  parameters.push("arguments: &[String]".into());
+
+  lines.push(format!(
+    "fn r#{name}({}) -> Result<(), ArgumentError> {{",
+    parameters.join(", "),
+  ));
+
+  lines.push(format!(
+    "  let [{}] = arguments else {{",
+    arguments.join(", ")
+  ));
+  lines.push("    return Err(ArgumentError::Count {".into());
+  lines.push(format!("      expected: {},", arguments.len()));
+  lines.push("      actual: arguments.len(),".into());
+  lines.push("    });".into());
+  lines.push("  };".into());
+
+  for argument in arguments {
+    lines.push(format!(
+      "  let {argument} = Argument::argument(\"{argument}\", {argument})?;"
+    ));
+  }
+
+  lines.push(format!(
+    "  commands::r#{name}({}, {});",
+    context.join(", "),
+    arguments.join(", "),
+  ));
+
+  lines.push("  Ok(())".into());
+
+  lines.push("}".into());
+
+  lines
+}
@@ -1,27 +1,247 @@
 use super::*;
 
 pub(crate) struct Analyzer {
+  bands: BTreeMap<Band, f32>,
   complex_frequencies: Vec<Complex<f32>>,
   frequencies: Vec<f32>,
+  history: Vec<f32>,
+  onsets: Vec<Band>,
+  pending: usize,
   planner: FftPlanner<f32>,
+  refractory: BTreeMap<Band, f32>,
   rms: f32,
+  row: u64,
   samples: Vec<f32>,
   scratch: Vec<Complex<f32>>,
+  spectrogram: VecDeque<Vec<f32>>,
+  stereo_complex_frequencies: [Vec<Complex<f32>>; 2],
+  stereo_frequencies: [Vec<f32>; 2],
+  stereo_history: [Vec<f32>; 2],
+  stereo_samples: [Vec<f32>; 2],
+  waveforms: VecDeque<Vec<f32>>,
+  width: f32,
 }
 
 impl Analyzer {
+  const DB_FLOOR: f32 = -60.0;
+
+  pub(crate) const HISTORY: u32 = 256;
+
+  fn analyze(&mut self, spectrum: SpectrumConfig, bandpass: Vec2f, spacing: f32) {
+    let end = self.history.len().saturating_sub(self.pending);
+
+    Self::fft(
+      &mut self.planner,
+      &mut self.scratch,
+      &self.history[..end],
+      spectrum,
+      &mut self.complex_frequencies,
+    );
+
+    for (history, complex_frequencies) in self
+      .stereo_history
+      .iter()
+      .zip(&mut self.stereo_complex_frequencies)
+    {
+      Self::fft(
+        &mut self.planner,
+        &mut self.scratch,
+        &history[..end.min(history.len())],
+        spectrum,
+        complex_frequencies,
+      );
+    }
+
+    self.row += 1;
+
+    let full = self.spectrogram.len() == Self::HISTORY.into_usize();
+
+    let mut frequencies = full
+      .then(|| self.spectrogram.pop_front())
+      .flatten()
+      .unwrap_or_default();
+
+    Self::remap(
+      &self.complex_frequencies,
+      spectrum,
+      bandpass,
+      spacing,
+      &mut frequencies,
+    );
+
+    if spectrum.db {
+      Self::decibels(&mut frequencies);
+    }
+
+    self.spectrogram.push_back(frequencies);
+
+    let mut waveform = full
+      .then(|| self.waveforms.pop_front())
+      .flatten()
+      .unwrap_or_default();
+
+    waveform.clear();
+    waveform.extend_from_slice(&self.history[end.saturating_sub(self.samples.len())..end]);
+
+    self.waveforms.push_back(waveform);
+  }
+
+  pub(crate) fn band(&self, band: Band) -> f32 {
+    self.bands.get(&band).copied().unwrap_or_default()
+  }
+
+  fn bins(
+    complex_frequencies: &[Complex<f32>],
+    frequencies: Vec2f,
+    spacing: f32,
+  ) -> impl Iterator<Item = f32> {
+    let half = complex_frequencies.len() / 2;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let threshold = (frequencies.x / spacing).ceil() as usize;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let cutoff = (frequencies.y / spacing).ceil() as usize;
+
+    complex_frequencies
+      .iter()
+      .enumerate()
+      .skip(threshold)
+      .take(cutoff.min(half + 1).saturating_sub(threshold))
+      .map(move |(i, c)| Self::magnitude(i, half, *c))
+  }
+
+  fn decibels(values: &mut [f32]) {
+    for value in values {
+      let db = (20.0 * value.log10()).max(Self::DB_FLOOR);
+      *value = (db - Self::DB_FLOOR) / -Self::DB_FLOOR;
+    }
+  }
+
+  fn fft(
+    planner: &mut FftPlanner<f32>,
+    scratch: &mut Vec<Complex<f32>>,
+    history: &[f32],
+    spectrum: SpectrumConfig,
+    output: &mut Vec<Complex<f32>>,
+  ) {
+    output.clear();
+
+    let history = &history[history.len().saturating_sub(spectrum.size)..];
+
+    if history.is_empty() {
+      return;
+    }
+
+    let padding = spectrum.size.saturating_sub(history.len());
+
+    output.resize(padding, Complex::default());
+    output.extend(history.iter().enumerate().map(|(i, sample)| {
+      Complex::from(sample * spectrum.window.coefficient(padding + i, spectrum.size))
+    }));
+
+    let fft = planner.plan_fft_forward(output.len());
+    let scratch_len = fft.get_inplace_scratch_len();
+    if scratch.len() < scratch_len {
+      scratch.resize(scratch_len, 0.0.into());
+    }
+    fft.process_with_scratch(output, &mut scratch[..scratch_len]);
+
+    let reference = spectrum.size as f32 * spectrum.window.gain();
+
+    for c in output {
+      *c /= reference;
+    }
+  }
+
   pub(crate) fn frequencies(&self) -> &[f32] {
     &self.frequencies
   }
 
+  pub(crate) fn history(&self, row: u64) -> Option<(&[f32], &[f32])> {
+    let age = self.row.checked_sub(row)?.into_usize();
+    let index = self.spectrogram.len().checked_sub(age + 1)?;
+    Some((&self.spectrogram[index], &self.waveforms[index]))
+  }
+
+  fn magnitude(i: usize, half: usize, c: Complex<f32>) -> f32 {
+    let weight = if i == 0 || i == half { 1.0 } else { 2.0 };
+    c.norm() * weight
+  }
+
   pub(crate) fn new() -> Self {
     Self {
+      bands: BTreeMap::new(),
       complex_frequencies: Vec::new(),
       frequencies: Vec::new(),
+      history: Vec::new(),
+      onsets: Vec::new(),
+      pending: 0,
       planner: FftPlanner::new(),
+      refractory: BTreeMap::new(),
       rms: 0.0,
+      row: 0,
       samples: Vec::new(),
       scratch: Vec::new(),
+      spectrogram: VecDeque::new(),
+      stereo_complex_frequencies: [Vec::new(), Vec::new()],
+      stereo_frequencies: [Vec::new(), Vec::new()],
+      stereo_history: [Vec::new(), Vec::new()],
+      stereo_samples: [Vec::new(), Vec::new()],
+      waveforms: VecDeque::new(),
+      width: 0.0,
+    }
+  }
+
+  pub(crate) fn onsets(&self) -> &[Band] {
+    &self.onsets
+  }
+
+  fn remap(
+    complex_frequencies: &[Complex<f32>],
+    spectrum: SpectrumConfig,
+    bandpass: Vec2f,
+    spacing: f32,
+    output: &mut Vec<f32>,
+  ) {
+    output.clear();
+
+    if complex_frequencies.is_empty() {
+      return;
+    }
+
+    let half = complex_frequencies.len() / 2;
+
+    let low = spectrum.scale.value(bandpass.x);
+    let high = spectrum.scale.value(bandpass.y);
+
+    for bin in 0..spectrum.bins {
+      let start = spectrum
+        .scale
+        .frequency(low + (high - low) * bin as f32 / spectrum.bins as f32);
+      let end = spectrum
+        .scale
+        .frequency(low + (high - low) * (bin + 1) as f32 / spectrum.bins as f32);
+
+      let value = if end - start < spacing {
+        let position = f32::midpoint(start, end) / spacing;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let i = position.floor() as usize;
+        let fraction = position.fract();
+        let magnitude = |i: usize| {
+          complex_frequencies
+            .get(i)
+            .filter(|_| i <= half)
+            .map_or(0.0, |c| Self::magnitude(i, half, *c))
+        };
+        magnitude(i) * (1.0 - fraction) + magnitude(i + 1) * fraction
+      } else {
+        Self::root_mean_square(Self::bins(
+          complex_frequencies,
+          Vec2f::new(start, end),
+          spacing,
+        ))
+      };
+
+      output.push(value);
     }
   }
 
@@ -29,62 +249,127 @@ impl Analyzer {
     self.rms
   }
 
+  fn root_mean_square(bins: impl Iterator<Item = f32>) -> f32 {
+    let (sum, count) = bins.fold((0.0, 0usize), |(sum, count), bin| {
+      (sum + bin * bin, count + 1)
+    });
+    (sum / count.max(1) as f32).sqrt()
+  }
+
+  pub(crate) fn row(&self) -> u64 {
+    self.row
+  }
+
   pub(crate) fn samples(&self) -> &[f32] {
     &self.samples
   }
 
+  pub(crate) fn stereo_frequencies(&self) -> &[Vec<f32>; 2] {
+    &self.stereo_frequencies
+  }
+
+  pub(crate) fn stereo_samples(&self) -> &[Vec<f32>; 2] {
+    &self.stereo_samples
+  }
+
   pub(crate) fn update(&mut self, sound: &Sound, done: bool, state: &State) {
+    fn window(samples: &mut Vec<f32>, new: impl Iterator<Item = f32>, done: bool, keep: usize) {
+      if done {
+        samples.clear();
+      } else {
+        let old = samples.len();
+        samples.extend(new);
+        samples.drain(..samples.len().saturating_sub(keep).min(old));
+      }
+    }
+
+    let spectrum = state.spectrum;
+
+    self.pending += sound.frames();
+
+    let keep = spectrum.size + self.pending;
+
+    window(&mut self.samples, sound.downmix(), done, 128);
+    window(&mut self.history, sound.downmix(), done, keep);
+
+    for (channel, (samples, history)) in self
+      .stereo_samples
+      .iter_mut()
+      .zip(&mut self.stereo_history)
+      .enumerate()
+    {
+      window(samples, sound.channel(channel), done, 128);
+      window(history, sound.channel(channel), done, keep);
+    }
+
+    let spacing = sound.format().sample_rate as f32 / spectrum.size.max(1) as f32;
+
     if done {
-      self.samples.clear();
-    } else {
-      let old = self.samples.len();
-      self.samples.extend(sound.downmix());
-      self
-        .samples
-        .drain(..self.samples.len().saturating_sub(128).min(old));
+      self.pending = 0;
+      self.analyze(spectrum, state.bandpass, spacing);
     }
 
-    let samples = &self.samples[..self.samples.len() & !1];
+    let hop = spectrum.hop();
 
-    self.complex_frequencies.clear();
-    self
-      .complex_frequencies
-      .extend(samples.iter().map(Complex::from));
-    let fft = self.planner.plan_fft_forward(samples.len());
-    let scratch_len = fft.get_inplace_scratch_len();
-    if self.scratch.len() < scratch_len {
-      self.scratch.resize(scratch_len, 0.0.into());
+    while self.pending >= hop {
+      self.pending -= hop;
+      self.analyze(spectrum, state.bandpass, spacing);
     }
-    fft.process_with_scratch(
-      &mut self.complex_frequencies,
-      &mut self.scratch[..scratch_len],
+
+    Self::remap(
+      &self.complex_frequencies,
+      spectrum,
+      state.bandpass,
+      spacing,
+      &mut self.frequencies,
     );
 
-    let n = self.complex_frequencies.len();
-    let half = n / 2;
-    let spacing = sound.format().sample_rate as f32 / n as f32;
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    let threshold = (state.bandpass.x / spacing) as usize;
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    let cutoff = (state.bandpass.y / spacing) as usize;
+    for (complex_frequencies, frequencies) in self
+      .stereo_complex_frequencies
+      .iter()
+      .zip(&mut self.stereo_frequencies)
+    {
+      Self::remap(
+        complex_frequencies,
+        spectrum,
+        state.bandpass,
+        spacing,
+        frequencies,
+      );
+    }
 
-    self.frequencies.clear();
-    self.frequencies.extend(
-      self
-        .complex_frequencies
-        .iter()
-        .enumerate()
-        .skip(threshold)
-        .take(cutoff.min(half).saturating_sub(threshold))
-        .map(|(i, c)| {
-          let weight = if i == 0 || i == half { 1.0 } else { 2.0 };
-          c.norm() * weight
-        }),
-    );
+    let dt = sound.duration().as_secs_f32();
+
+    self.onsets.clear();
+
+    for (&band, config) in &state.bands {
+      let rms = Self::root_mean_square(Self::bins(
+        &self.complex_frequencies,
+        config.frequencies,
+        spacing,
+      ));
+
+      let level = self.bands.entry(band).or_default();
 
-    let rms = (self.frequencies.iter().map(|&f| f * f).sum::<f32>()
-      / self.frequencies.len().max(1) as f32)
-      .sqrt();
+      let elapsed = self.refractory.entry(band).or_insert(f32::INFINITY);
+
+      *elapsed += dt;
+
+      if rms - *level > config.threshold && *elapsed >= config.refractory {
+        self.onsets.push(band);
+        *elapsed = 0.0;
+      }
+
+      let alpha = if rms > *level {
+        config.attack
+      } else {
+        config.release
+      };
+
+      *level = alpha * rms + (1.0 - alpha) * *level;
+    }
+
+    let rms = Self::root_mean_square(self.frequencies.iter().copied());
 
     let alpha = if rms > self.rms {
       state.alpha
@@ -93,5 +378,329 @@ impl Analyzer {
     };
 
     self.rms = alpha * rms + (1.0 - alpha) * self.rms;
+
+    if spectrum.db {
+      Self::decibels(&mut self.frequencies);
+
+      for frequencies in &mut self.stereo_frequencies {
+        Self::decibels(frequencies);
+      }
+    }
+
+    let [left, right] = &self.stereo_samples;
+
+    let (mid, side) = left
+      .iter()
+      .zip(right)
+      .fold((0.0, 0.0), |(mid, side), (left, right)| {
+        (mid + (left + right).powi(2), side + (left - right).powi(2))
+      });
+
+    let width = if mid + side > 0.0 {
+      side.sqrt() / (mid.sqrt() + side.sqrt())
+    } else {
+      0.0
+    };
+
+    self.width = state.alpha * width + (1.0 - state.alpha) * self.width;
+  }
+
+  pub(crate) fn width(&self) -> f32 {
+    self.width
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bands() {
+    #[track_caller]
+    fn case(frequency: f32, loud: Band) {
+      let format = SoundFormat {
+        channels: 1,
+        sample_rate: 48_000,
+      };
+
+      let samples = (0..9_600)
+        .map(|i| (i as f32 / 48_000.0 * frequency * TAU).sin())
+        .collect::<Vec<f32>>();
+
+      let mut analyzer = Analyzer::new();
+
+      let mut state = State::default();
+
+      state.spectrum.size = 8192;
+
+      for _ in 0..10 {
+        analyzer.update(&Sound::new(format, samples.clone()), false, &state);
+      }
+
+      for band in Band::iter() {
+        if band == loud {
+          assert!(
+            analyzer.band(band) > 0.01,
+            "{band:?}: {}",
+            analyzer.band(band)
+          );
+        } else {
+          assert!(
+            analyzer.band(band) < 0.001,
+            "{band:?}: {}",
+            analyzer.band(band)
+          );
+        }
+      }
+    }
+
+    case(100.0, Band::Kick);
+    case(1_000.0, Band::Mid);
+    case(10_000.0, Band::Hats);
+  }
+
+  #[test]
+  fn history() {
+    let format = SoundFormat {
+      channels: 1,
+      sample_rate: 48_000,
+    };
+
+    let mut analyzer = Analyzer::new();
+
+    let state = State::default();
+
+    let sound = Sound::new(format, vec![0.5; state.spectrum.hop()]);
+
+    assert!(analyzer.history(0).is_none());
+
+    analyzer.update(&sound, false, &state);
+
+    assert!(analyzer.history(0).is_none());
+    let (frequencies, samples) = analyzer.history(1).unwrap();
+    assert_eq!(frequencies.len(), state.spectrum.bins);
+    assert_eq!(samples, analyzer.samples());
+    assert!(analyzer.history(2).is_none());
+
+    for _ in 0..Analyzer::HISTORY {
+      analyzer.update(&sound, false, &state);
+    }
+
+    let row = analyzer.row();
+    assert!(
+      analyzer
+        .history(row - u64::from(Analyzer::HISTORY))
+        .is_none()
+    );
+    assert!(
+      analyzer
+        .history(row - u64::from(Analyzer::HISTORY) + 1)
+        .is_some()
+    );
+    assert!(analyzer.history(row).is_some());
+  }
+
+  #[test]
+  fn normalized() {
+    #[track_caller]
+    fn case(size: usize, window: WindowFunction) -> f32 {
+      let format = SoundFormat {
+        channels: 1,
+        sample_rate: 48_000,
+      };
+
+      let samples = (0..size)
+        .map(|i| (i as f32 / 48_000.0 * 100.0 * TAU).sin())
+        .collect::<Vec<f32>>();
+
+      let mut analyzer = Analyzer::new();
+
+      let mut state = State::default();
+
+      state.spectrum.size = size;
+      state.spectrum.window = window;
+
+      analyzer.update(&Sound::new(format, samples), false, &state);
+
+      analyzer.frequencies().iter().copied().fold(0.0, f32::max)
+    }
+
+    for window in [
+      WindowFunction::Blackman,
+      WindowFunction::Hann,
+      WindowFunction::Rectangular,
+    ] {
+      for size in [1024, 4096, 16384] {
+        let level = case(size, window);
+        assert!((0.5..=1.0).contains(&level), "{window:?} {size}: {level}");
+      }
+    }
+  }
+
+  #[test]
+  fn onsets() {
+    let format = SoundFormat {
+      channels: 1,
+      sample_rate: 48_000,
+    };
+
+    let state = State::default();
+
+    let frames = state.spectrum.hop() * 2;
+
+    let silence = Sound::new(format, vec![0.0; frames]);
+
+    let kick = Sound::new(
+      format,
+      (0..frames)
+        .map(|i| (i as f32 / 48_000.0 * 100.0 * TAU).sin())
+        .collect(),
+    );
+
+    let mut analyzer = Analyzer::new();
+
+    let kick_onset = |analyzer: &Analyzer| {
+      assert!(!analyzer.onsets().contains(&Band::Hats));
+      analyzer.onsets().contains(&Band::Kick)
+    };
+
+    analyzer.update(&silence, false, &state);
+    assert_eq!(analyzer.onsets(), []);
+
+    analyzer.update(&kick, false, &state);
+    assert!(kick_onset(&analyzer));
+
+    analyzer.update(&kick, false, &state);
+    assert!(!kick_onset(&analyzer));
+
+    for _ in 0..10 {
+      analyzer.update(&silence, false, &state);
+    }
+    assert_eq!(analyzer.onsets(), []);
+
+    analyzer.update(&kick, false, &state);
+    assert!(kick_onset(&analyzer));
+
+    analyzer.update(&silence, false, &state);
+    analyzer.update(&kick, false, &state);
+    assert!(!kick_onset(&analyzer));
+  }
+
+  #[test]
+  fn row() {
+    let format = SoundFormat {
+      channels: 1,
+      sample_rate: 48_000,
+    };
+
+    let mut analyzer = Analyzer::new();
+
+    let state = State::default();
+
+    let hop = state.spectrum.hop();
+
+    assert_eq!(analyzer.row(), 0);
+
+    analyzer.update(&Sound::new(format, vec![0.0; hop / 2]), false, &state);
+    assert_eq!(analyzer.row(), 0);
+
+    analyzer.update(&Sound::new(format, vec![0.0; hop / 2]), false, &state);
+    assert_eq!(analyzer.row(), 1);
+
+    analyzer.update(&Sound::new(format, vec![0.0; hop * 2]), false, &state);
+    assert_eq!(analyzer.row(), 3);
+
+    analyzer.update(&Sound::new(format, vec![0.0; hop * 5 / 2]), false, &state);
+    assert_eq!(analyzer.row(), 5);
+
+    analyzer.update(&Sound::new(format, vec![0.0; hop / 2]), false, &state);
+    assert_eq!(analyzer.row(), 6);
+  }
+
+  #[test]
+  fn spectrum() {
+    #[track_caller]
+    fn case(scale: FrequencyScale, db: bool, expected: usize) {
+      let format = SoundFormat {
+        channels: 1,
+        sample_rate: 48_000,
+      };
+
+      let samples = (0..4_800)
+        .map(|i| (i as f32 / 48_000.0 * 93.75 * TAU).sin())
+        .collect::<Vec<f32>>();
+
+      let mut analyzer = Analyzer::new();
+
+      let mut state = State::default();
+
+      state.spectrum.db = db;
+      state.spectrum.scale = scale;
+
+      analyzer.update(&Sound::new(format, samples), false, &state);
+
+      let frequencies = analyzer.frequencies();
+
+      assert_eq!(frequencies.len(), state.spectrum.bins);
+
+      let peak = frequencies
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .unwrap()
+        .0;
+
+      assert!(peak.abs_diff(expected) <= 2, "{scale:?}: {peak}");
+
+      if db {
+        assert!(frequencies.iter().all(|value| (0.0..=1.0).contains(value)));
+        assert!(frequencies[peak] > 0.9, "{}", frequencies[peak]);
+      }
+    }
+
+    case(FrequencyScale::Linear, false, 49);
+    case(FrequencyScale::Log, false, 131);
+    case(FrequencyScale::Log, true, 131);
+    case(FrequencyScale::Mel, false, 58);
+  }
+
+  #[test]
+  fn stereo() {
+    fn case(channel: impl Fn(f32) -> [f32; 2]) -> Analyzer {
+      let format = SoundFormat {
+        channels: 2,
+        sample_rate: 48_000,
+      };
+
+      let samples = (0..800)
+        .flat_map(|i| channel((i as f32 / 48_000.0 * 100.0 * TAU).sin()))
+        .collect::<Vec<f32>>();
+
+      let mut analyzer = Analyzer::new();
+
+      let state = State::default();
+
+      for _ in 0..100 {
+        analyzer.update(&Sound::new(format, samples.clone()), false, &state);
+      }
+
+      analyzer
+    }
+
+    let level = |frequencies: &[f32]| frequencies.iter().copied().fold(0.0, f32::max);
+
+    let analyzer = case(|sample| [sample, sample]);
+    assert!(analyzer.width() < 0.01, "{}", analyzer.width());
+
+    let analyzer = case(|sample| [sample, -sample]);
+    assert!(analyzer.width() > 0.99, "{}", analyzer.width());
+
+    let analyzer = case(|sample| [sample, 0.0]);
+    let [left, right] = analyzer.stereo_frequencies();
+    assert!(level(left) > 0.1);
+    assert_eq!(level(right), 0.0);
+    let [left, right] = analyzer.stereo_samples();
+    assert_eq!(left.len(), right.len());
+    assert!(right.iter().all(|sample| *sample == 0.0));
   }
 }
@@ -9,6 +9,7 @@ pub(crate) struct App {
   pub(crate) cursor_moved: Instant,
   pub(crate) cursors: HashSet<DeviceId>,
   pub(crate) deadline: Instant,
+  pub(crate) elapsed: f64,
   pub(crate) errors: Vec<Error>,
   pub(crate) frame_times: VecDeque<Instant>,
   pub(crate) fullscreen: bool,
@@ -17,9 +18,11 @@ pub(crate) struct App {
   pub(crate) input: Option<Input>,
   pub(crate) interrupt: Interrupt,
   pub(crate) last: Instant,
+  pub(crate) log: Option<SessionLog>,
   pub(crate) mode: Mode,
   pub(crate) modifiers: Modifiers,
   pub(crate) options: Options,
+  pub(crate) osc: Option<OscServer>,
   pub(crate) patch: Patch,
   pub(crate) present_mode: Option<PresentMode>,
   pub(crate) record: Option<Fps>,
@@ -27,6 +30,8 @@ pub(crate) struct App {
   pub(crate) renderer: Option<Renderer>,
   pub(crate) rng: SmallRng,
   pub(crate) script: Option<Script>,
+  pub(crate) seed: u64,
+  pub(crate) server: Option<Server>,
   pub(crate) spf: Option<usize>,
   pub(crate) state: State,
   pub(crate) tap: Tap,
@@ -34,40 +39,81 @@ pub(crate) struct App {
 }
 
 impl App {
-  pub(crate) fn dispatch(&mut self, event_loop: &ActiveEventLoop, command: Command) {
-    use Command::*;
+  fn append_log(&mut self, invocation: &Invocation) {
+    if let Some(log) = &mut self.log
+      && let Err(err) = log.append(invocation)
+    {
+      eprintln!("failed to write session log, disabling: {err}");
+      self.log = None;
+    }
+  }
 
-    match command {
-      App(command) => command(self),
-      AppEventLoop(command) => command(self, event_loop),
-      AppFallible(command) => {
+  pub(crate) fn dispatch(
+    &mut self,
+    event_loop: &ActiveEventLoop,
+    entry: CommandEntry,
+    arguments: &[String],
+  ) {
+    match entry.command {
+      Command::App(command) => command(self),
+      Command::AppEventLoop(command) => command(self, event_loop),
+      Command::AppFallible(command) => {
         if let Err(err) = command(self) {
           self.errors.push(err);
           event_loop.exit();
         }
       }
-      RngState(command) => {
-        self.history.states.push(self.state.clone());
-        command(&mut self.rng, &mut self.state);
-      }
-      State(command) => {
-        self.history.states.push(self.state.clone());
-        command(&mut self.state);
-      }
-      HistoryState(command) => {
-        command(&mut self.history, &mut self.state);
+      Command::History(_)
+      | Command::HistoryState(_)
+      | Command::RngState(_)
+      | Command::RngStateArguments(_)
+      | Command::State(_)
+      | Command::StateArguments(_) => {
+        let invocation = self.invocation(entry, arguments);
+
+        let logged = self.log.is_some().then(|| invocation.clone());
+
+        match self
+          .history
+          .dispatch(&mut self.rng, &mut self.state, invocation)
+        {
+          Ok(()) => {
+            if let Some(invocation) = logged {
+              self.append_log(&invocation);
+            }
+          }
+          Err(err) => eprintln!("{err}"),
+        }
       }
-      History(command) => {
-        command(&mut self.history);
+      Command::Tap(_) | Command::TapArguments(_) => {
+        let invocation = self.invocation(entry, arguments);
+
+        let tempo = self.tap.tempo();
+
+        match self.tap.dispatch(&invocation) {
+          Ok(()) => self.append_log(&invocation),
+          Err(err) => eprintln!("{err}"),
+        }
+
+        if self.tap.tempo() != tempo {
+          commands::print_tempo(self);
+        }
       }
     }
+  }
 
-    match command {
-      App(_) | AppEventLoop(_) | AppFallible(_) => {}
-      RngState(_) | State(_) | HistoryState(_) | History(_) => {
-        self.history.commands.push(command);
-      }
+  pub(crate) fn execute(&mut self, event_loop: &ActiveEventLoop, name: &str, arguments: &[String]) {
+    let Some(entry) = self.commands.name(name) else {
+      eprintln!("unknown command: {name}");
+      return;
+    };
+
+    if !entry.command.takes_arguments() && !arguments.is_empty() {
+      eprintln!("{name} takes no arguments");
+      return;
     }
+
+    self.dispatch(event_loop, entry, arguments);
   }
 
   fn exit(&mut self) -> Result {
@@ -78,7 +124,7 @@ impl App {
     }
 
     if let Some(recorder_thread) = self.recorder_thread.take() {
-      recorder_thread.finish(&self.options, &self.config)?;
+      recorder_thread.finish(&self.options, &self.config, None)?;
     }
 
     Ok(())
@@ -162,6 +208,7 @@ impl App {
     if let Some(fps) = self.record {
       self.recorder_thread = Some(RecorderThread::new(Recorder::new(
         fps,
+        VideoFormat::H264,
         &self.options,
         true,
         renderer.size(),
@@ -180,10 +227,23 @@ impl App {
     Ok(())
   }
 
+  fn invocation(&self, entry: CommandEntry, arguments: &[String]) -> Invocation {
+    Invocation {
+      arguments: arguments.to_vec(),
+      entry,
+      position: self.tap.position(),
+      time: self.elapsed,
+    }
+  }
+
   pub(crate) fn new(
     config: Config,
     fullscreen: bool,
+    hostname: Option<String>,
+    listen: Option<SocketAddr>,
+    log: Option<Utf8PathBuf>,
     options: Options,
+    osc_port: Option<u16>,
     present_mode: Option<PresentMode>,
     record: Option<Fps>,
   ) -> Result<Self> {
@@ -235,7 +295,9 @@ impl App {
 
     options.add_source(&config, &mut tap)?;
 
-    let mut rng = options.rng();
+    let seed = options.seed();
+
+    let mut rng = SmallRng::seed_from_u64(seed);
 
     let state = options.state(&config, &mut rng)?;
 
@@ -252,28 +314,41 @@ impl App {
       None
     };
 
-    let script = options.script();
+    let script = options.script()?;
+
+    let bindings = Bindings::new(config.bindings())?;
+
+    let hub = Hub::new(config.controllers())?;
+
+    let commands = Commands::new();
+
+    let server = listen
+      .map(|address| Server::new(address, &commands, hostname.as_deref()))
+      .transpose()?;
 
     Ok(Self {
       allocated: 0,
       analyzer: Analyzer::new(),
-      bindings: Bindings::new(),
-      commands: Commands::new(),
+      bindings,
+      commands,
       config,
       cursor_moved: now,
       cursors: HashSet::new(),
       deadline: now,
+      elapsed: 0.0,
       errors: Vec::new(),
       frame_times: VecDeque::with_capacity(100),
       fullscreen,
       history: History::default(),
-      hub: Hub::new()?,
+      hub,
       input,
       interrupt: Interrupt::register()?,
       last: now,
+      log: log.map(|path| SessionLog::new(path, seed)).transpose()?,
       mode: Mode::Normal,
       modifiers: Modifiers::default(),
       options,
+      osc: osc_port.map(OscServer::new).transpose()?,
       patch: Patch::default(),
       present_mode,
       record,
@@ -281,6 +356,8 @@ impl App {
       renderer: None,
       rng,
       script,
+      seed,
+      server,
       spf,
       state,
       tap,
@@ -300,15 +377,19 @@ impl App {
     }
 
     if let Mode::Command(command) = &mut self.mode
-      && let Key::Character(c) = &key
+      && let Some(text) = match &key {
+        Key::Character(c) => Some(c.as_str()),
+        Key::Named(NamedKey::Space) => Some(" "),
+        _ => None,
+      }
     {
-      command.push(c.as_str().into());
+      command.push(text.into());
       self.print_command();
       return;
     }
 
-    if let Some(command) = self.bindings.key((&self.mode).into(), &key, self.modifiers) {
-      self.dispatch(event_loop, command);
+    if let Some(entry) = self.bindings.key((&self.mode).into(), &key, self.modifiers) {
+      self.dispatch(event_loop, entry, &[]);
     }
   }
 
@@ -326,22 +407,73 @@ impl App {
   fn process_messages(&mut self, event_loop: &ActiveEventLoop) {
     for message in self.hub.drain() {
       match message.event {
+        Event::Aftertouch(value) | Event::Control(value) | Event::Encoder(value) => {
+          if let Some(parameter) = self.bindings.encoder(&message.controller, message.control) {
+            self.set(event_loop, parameter, parameter.value(value));
+          }
+        }
         Event::Button(press) => {
-          if let Some(command) = self
+          if let Some(entry) = self
             .bindings
-            .button(message.controller, message.control, press)
+            .button(&message.controller, message.control, press)
           {
-            self.dispatch(event_loop, command);
+            self.dispatch(event_loop, entry, &[]);
           }
         }
-        Event::Encoder(parameter) => {
-          if let Some(command) = self.bindings.encoder(message.controller, message.control) {
-            let value = command(&mut self.state, parameter);
-            self.state.encoder = value;
+        Event::PitchBend(bend) => {
+          if let Some(parameter) = self.bindings.encoder(&message.controller, message.control) {
+            let value = u7::new(u8::try_from(u16::from(bend) >> 7).unwrap());
+            self.set(event_loop, parameter, parameter.value(value));
           }
         }
       }
     }
+
+    for message in self.osc.as_ref().map(OscServer::drain).unwrap_or_default() {
+      self.process_osc_message(event_loop, message);
+    }
+
+    for (name, arguments) in self.server.as_ref().map(Server::drain).unwrap_or_default() {
+      self.execute(event_loop, &name, &arguments);
+    }
+  }
+
+  fn process_osc_message(&mut self, event_loop: &ActiveEventLoop, message: OscMessage) {
+    if let Some(name) = message.address.strip_prefix("/command/") {
+      let Some(entry) = self.commands.name(name) else {
+        eprintln!("unknown OSC command: {name}");
+        return;
+      };
+
+      if entry.command.takes_arguments() {
+        self.dispatch(event_loop, entry, &message.arguments);
+      } else if !message
+        .arguments
+        .first()
+        .is_some_and(|value| value == "false" || value.parse::<f32>() == Ok(0.0))
+      {
+        self.dispatch(event_loop, entry, &[]);
+      }
+    } else if let Some(path) = message.address.strip_prefix("/state/") {
+      let Some(parameter) = Parameter::from_path(path) else {
+        eprintln!("unknown OSC parameter: {path}");
+        return;
+      };
+
+      let [value] = message.arguments.as_slice() else {
+        eprintln!("OSC parameter {path} takes one argument");
+        return;
+      };
+
+      let Some(value) = f32::parse(value) else {
+        eprintln!("invalid value for OSC parameter {path}: {value}");
+        return;
+      };
+
+      self.set(event_loop, parameter, value);
+    } else {
+      eprintln!("unknown OSC address: {}", message.address);
+    }
   }
 
   fn redraw(&mut self, event_loop: &ActiveEventLoop) -> Result {
@@ -356,8 +488,14 @@ impl App {
 
     self.process_messages(event_loop);
 
+    self.tap.sync(self.hub.clock());
+
     let sound = if let Some(input) = &self.input {
-      input.drain_exact(self.spf)
+      let sound = input.drain_exact(self.spf);
+      if let Some(sound) = &sound {
+        self.tap.elapse(sound.duration());
+      }
+      sound
     } else {
       self.tap.drain_exact(self.spf)
     };
@@ -373,6 +511,13 @@ impl App {
       &self.state,
     );
 
+    for band in self.analyzer.onsets().to_vec() {
+      if let Some(entry) = self.bindings.onset(band) {
+        log::info!("{} onset: {}", band.name(), entry.name);
+        self.dispatch(event_loop, entry, &[]);
+      }
+    }
+
     let now = if let Some(fps) = self.record {
       self.last + fps.dt()
     } else {
@@ -399,6 +544,8 @@ impl App {
 
     let tick = self.tap.tick(dt);
 
+    self.elapsed += dt.as_secs_f64();
+
     let commands = self
       .script
       .as_ref()
@@ -406,19 +553,25 @@ impl App {
       .unwrap_or_default()
       .to_vec();
 
-    for CommandEntry { name, command } in commands {
-      log::info!("dispatching script command {name}");
-      self.dispatch(event_loop, command);
+    for entry in commands {
+      log::info!("dispatching script command {}", entry.name);
+      self.dispatch(event_loop, entry, &[]);
     }
 
     self.state.tick(tick);
 
+    self.hub.feedback(&self.bindings, &self.state);
+
     let renderer = self.renderer.as_mut().unwrap();
 
     let frame = renderer.frame();
 
     renderer.render(&self.analyzer, &self.state, fps)?;
 
+    if let Some(server) = &self.server {
+      server.update(&Status::new(&self.state, fps));
+    }
+
     if let Some(recorder) = &self.recorder_thread {
       let tx = recorder.tx().clone();
       renderer.capture({
@@ -435,7 +588,7 @@ impl App {
     {
       mem::take(&mut self.recorder_thread)
         .unwrap()
-        .finish(&self.options, &self.config)?;
+        .finish(&self.options, &self.config, None)?;
       log::warn!("recording unexpectedly finished");
     }
 
@@ -512,6 +665,14 @@ impl App {
     }
   }
 
+  fn set(&mut self, event_loop: &ActiveEventLoop, parameter: Parameter, value: f32) {
+    self.dispatch(
+      event_loop,
+      generated::SET,
+      &[parameter.to_string(), value.to_string()],
+    );
+  }
+
   fn size(&self, size: PhysicalSize<u32>) -> (Size, NonZeroU32) {
     self.options.size(Size::new(
       size.width.max(1).try_into().unwrap(),
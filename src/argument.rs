@@ -0,0 +1,45 @@
+use {super::*, argument_error::ValueError};
+
+pub(crate) trait Argument: Sized {
+  fn argument(name: &'static str, value: &str) -> Result<Self, ArgumentError> {
+    Self::parse(value).context(ValueError { name, value })
+  }
+
+  fn parse(value: &str) -> Option<Self>;
+}
+
+impl Argument for Band {
+  fn parse(value: &str) -> Option<Self> {
+    ValueEnum::from_str(value, false).ok()
+  }
+}
+
+impl Argument for f32 {
+  fn parse(value: &str) -> Option<Self> {
+    value.parse().ok().filter(|value: &Self| value.is_finite())
+  }
+}
+
+impl Argument for Parameter {
+  fn parse(value: &str) -> Option<Self> {
+    Parameter::from_path(value)
+  }
+}
+
+impl Argument for Position {
+  fn parse(value: &str) -> Option<Self> {
+    Position::parse(value)
+  }
+}
+
+impl Argument for Preset {
+  fn parse(value: &str) -> Option<Self> {
+    ValueEnum::from_str(value, false).ok()
+  }
+}
+
+impl Argument for Scene {
+  fn parse(value: &str) -> Option<Self> {
+    ValueEnum::from_str(value, false).ok()
+  }
+}
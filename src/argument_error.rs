@@ -0,0 +1,10 @@
+use super::*;
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Error)), visibility(pub(crate)))]
+pub(crate) enum ArgumentError {
+  #[snafu(display("expected {expected} arguments but got {actual}"))]
+  Count { actual: usize, expected: usize },
+  #[snafu(display("invalid {name} `{value}`"))]
+  Value { name: &'static str, value: String },
+}
@@ -0,0 +1,190 @@
+use {
+  super::*,
+  symphonia::core::{
+    audio::SampleBuffer,
+    codecs::{CODEC_TYPE_NULL, DecoderOptions},
+    errors::Error as SymphoniaError,
+    formats::FormatOptions,
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+  },
+};
+
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq)]
+pub(crate) enum AudioFormat {
+  Aac,
+  Aiff,
+  Caf,
+  Flac,
+  Mp3,
+  Mp4,
+  Ogg,
+  Wav,
+}
+
+impl AudioFormat {
+  pub(crate) fn decode(self, path: &Utf8Path) -> Result<Wave> {
+    let file = File::open(path).context(error::FilesystemIo { path })?;
+
+    let mut hint = Hint::new();
+    hint.with_extension(self.extensions()[0]);
+
+    let mut reader = symphonia::default::get_probe()
+      .format(
+        &hint,
+        MediaSourceStream::new(Box::new(file), default()),
+        &FormatOptions {
+          enable_gapless: true,
+          ..default()
+        },
+        &MetadataOptions::default(),
+      )
+      .context(error::AudioDecode { path })?
+      .format;
+
+    let track = reader
+      .tracks()
+      .iter()
+      .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+      .context(error::AudioTrack { path })?;
+
+    let id = track.id;
+
+    let mut decoder = symphonia::default::get_codecs()
+      .make(&track.codec_params, &DecoderOptions::default())
+      .context(error::AudioDecode { path })?;
+
+    let mut buffer = None::<SampleBuffer<f32>>;
+    let mut channels = Vec::<Vec<f32>>::new();
+    let mut sample_rate = None;
+
+    loop {
+      let packet = match reader.next_packet() {
+        Ok(packet) => packet,
+        Err(SymphoniaError::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+        Err(source) => return Err(error::AudioDecode { path }.into_error(source)),
+      };
+
+      if packet.track_id() != id {
+        continue;
+      }
+
+      let decoded = match decoder.decode(&packet) {
+        Ok(decoded) => decoded,
+        Err(SymphoniaError::DecodeError(err)) => {
+          log::warn!("skipping corrupt packet in {path}: {err}");
+          continue;
+        }
+        Err(source) => return Err(error::AudioDecode { path }.into_error(source)),
+      };
+
+      let spec = *decoded.spec();
+
+      if channels.is_empty() {
+        channels.resize(spec.channels.count(), Vec::new());
+      }
+
+      ensure!(
+        channels.len() == spec.channels.count()
+          && *sample_rate.get_or_insert(spec.rate) == spec.rate,
+        error::AudioSpec { path },
+      );
+
+      let capacity = decoded.capacity().into_u64();
+
+      let buffer = match &mut buffer {
+        Some(buffer) if buffer.capacity() >= decoded.capacity() * spec.channels.count() => buffer,
+        buffer => buffer.insert(SampleBuffer::new(capacity, spec)),
+      };
+
+      buffer.copy_interleaved_ref(decoded);
+
+      for frame in buffer.samples().chunks(channels.len()) {
+        for (channel, &sample) in channels.iter_mut().zip(frame) {
+          channel.push(sample);
+        }
+      }
+    }
+
+    let mut wave = Wave::new(0, sample_rate.unwrap_or(DEFAULT_SAMPLE_RATE).into());
+
+    for channel in channels {
+      wave.push_channel(&channel);
+    }
+
+    Ok(wave)
+  }
+
+  pub(crate) fn extensions(self) -> &'static [&'static str] {
+    match self {
+      Self::Aac => &["aac"],
+      Self::Aiff => &["aiff", "aif"],
+      Self::Caf => &["caf"],
+      Self::Flac => &["flac"],
+      Self::Mp3 => &["mp3"],
+      Self::Mp4 => &["m4a", "mp4"],
+      Self::Ogg => &["ogg", "oga"],
+      Self::Wav => &["wav"],
+    }
+  }
+
+  pub(crate) fn from_path(path: &Utf8Path) -> Option<Self> {
+    let extension = path.extension()?;
+    Self::iter().find(|format| {
+      format
+        .extensions()
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(extension))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    fundsp::prelude::{pan, sine_hz},
+  };
+
+  #[test]
+  fn decode() {
+    let (_tempdir, path) = tempdir().unwrap();
+
+    let path = path.join("sine.wav");
+
+    let wave = Wave::render(44_100.0, 0.5, &mut (sine_hz::<f32>(440.0) >> pan(0.0)));
+
+    wave.save_wav32(&path).unwrap();
+
+    let decoded = AudioFormat::Wav.decode(&path).unwrap();
+
+    assert_eq!(decoded.channels(), 2);
+    assert_eq!(decoded.sample_rate(), 44_100.0);
+    assert_eq!(decoded.len(), wave.len());
+    assert_eq!(decoded.channel(0), wave.channel(0));
+    assert_eq!(decoded.channel(1), wave.channel(1));
+  }
+
+  #[test]
+  fn from_path() {
+    assert_eq!(
+      AudioFormat::from_path("a/b.FLAC".into()),
+      Some(AudioFormat::Flac),
+    );
+    assert_eq!(
+      AudioFormat::from_path("b.aif".into()),
+      Some(AudioFormat::Aiff)
+    );
+    assert_eq!(
+      AudioFormat::from_path("b.m4a".into()),
+      Some(AudioFormat::Mp4)
+    );
+    assert_eq!(
+      AudioFormat::from_path("b.mp3".into()),
+      Some(AudioFormat::Mp3)
+    );
+    assert_eq!(AudioFormat::from_path("b.mp3.tempo.yaml".into()), None);
+    assert_eq!(AudioFormat::from_path("b".into()), None);
+  }
+}
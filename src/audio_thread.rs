@@ -0,0 +1,67 @@
+use super::*;
+
+pub(crate) struct AudioThread {
+  handle: JoinHandle<()>,
+  rx: mpsc::Receiver<(f64, Sound, bool, Tick)>,
+}
+
+impl AudioThread {
+  const DEPTH: usize = 64;
+
+  pub(crate) fn finish(self) -> Result {
+    drop(self.rx);
+
+    match self.handle.join() {
+      Ok(()) => Ok(()),
+      Err(panic_value) => Err(error::AudioJoin { panic_value }.build()),
+    }
+  }
+
+  pub(crate) fn new(
+    mut tap: Tap,
+    dt: Duration,
+    spf: usize,
+    invocations: Vec<Invocation>,
+  ) -> Result<Self> {
+    let (tx, rx) = mpsc::sync_channel(Self::DEPTH);
+
+    let handle = thread_spawn("audio", move || {
+      let mut samples = vec![0.0; spf];
+
+      let mut invocations = invocations.into_iter().peekable();
+
+      let mut elapsed = 0.0;
+
+      loop {
+        elapsed += dt.as_secs_f64();
+
+        while let Some(invocation) = invocations.next_if(|invocation| invocation.time < elapsed) {
+          log::info!("replaying command {}", invocation.entry.name);
+          if let Err(err) = tap.dispatch(&invocation) {
+            log::warn!("failed to replay command {}: {err}", invocation.entry.name);
+          }
+        }
+
+        let time = tap.time();
+
+        let done = tap.is_done();
+
+        tap.write(&mut samples);
+
+        let sound = tap.drain();
+
+        let tick = tap.tick(dt);
+
+        if tx.send((time, sound, done, tick)).is_err() {
+          break;
+        }
+      }
+    })?;
+
+    Ok(Self { handle, rx })
+  }
+
+  pub(crate) fn recv(&self) -> Option<(f64, Sound, bool, Tick)> {
+    self.rx.recv().ok()
+  }
+}
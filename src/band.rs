@@ -0,0 +1,51 @@
+use super::*;
+
+#[derive(
+  Clone,
+  Copy,
+  Debug,
+  Deserialize,
+  EnumIter,
+  Eq,
+  IntoStaticStr,
+  Ord,
+  PartialEq,
+  PartialOrd,
+  Serialize,
+  ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum Band {
+  Hats,
+  Kick,
+  Mid,
+  Sub,
+}
+
+impl Band {
+  pub(crate) fn config(self) -> BandConfig {
+    let (low, high, attack, release, threshold, refractory) = match self {
+      Self::Hats => (6_000.0, 16_000.0, 0.9, 0.3, 0.002, 0.05),
+      Self::Kick => (60.0, 150.0, 0.8, 0.2, 0.02, 0.1),
+      Self::Mid => (400.0, 2_000.0, 0.5, 0.1, 0.01, 0.1),
+      Self::Sub => (20.0, 60.0, 0.5, 0.1, 0.02, 0.2),
+    };
+
+    BandConfig {
+      attack,
+      frequencies: Vec2f::new(low, high),
+      refractory,
+      release,
+      threshold,
+    }
+  }
+
+  pub(crate) fn defaults() -> BTreeMap<Self, BandConfig> {
+    Self::iter().map(|band| (band, band.config())).collect()
+  }
+
+  pub(crate) fn name(self) -> &'static str {
+    self.into()
+  }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BandConfig {
+  pub(crate) attack: f32,
+  pub(crate) frequencies: Vec2f,
+  pub(crate) refractory: f32,
+  pub(crate) release: f32,
+  pub(crate) threshold: f32,
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct BindingConfig {
+  pub(crate) buttons: Vec<ButtonBinding>,
+  pub(crate) encoders: Vec<EncoderBinding>,
+  pub(crate) keys: Vec<KeyBinding>,
+  pub(crate) onsets: Vec<OnsetBinding>,
+}
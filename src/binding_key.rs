@@ -0,0 +1,8 @@
+use super::*;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BindingKey {
+  Character(char),
+  Named(NamedKey),
+}
@@ -4,29 +4,32 @@ const OFF: ModifiersState = ModifiersState::empty();
 const CTRL: ModifiersState = ModifiersState::CONTROL;
 const CTRL_SUPER: ModifiersState = CTRL.union(SUPER);
 const SHIFT: ModifiersState = ModifiersState::SHIFT;
+const SHIFT_SUPER: ModifiersState = SHIFT.union(SUPER);
 const SUPER: ModifiersState = ModifiersState::SUPER;
 
 #[rustfmt::skip]
 const BUTTON_BINDINGS: &[(Controller, u8, Press, CommandEntry)] = {
-  use {Controller::*, generated::*, Press::Press};
+  use {generated::*, Press::Press};
+  const SPECTRA: Controller = Controller::SPECTRA;
+  const TWISTER: Controller = Controller::TWISTER;
   &[
-    (Spectra,  0, Press, PUSH_TOP),
-    (Spectra,  1, Press, PUSH_BOTTOM),
-    (Spectra,  2, Press, CYCLE),
-    (Spectra,  3, Press, CYCLE_ZOOM),
-
-    (Spectra,  4, Press, ROTATE_LEFT),
-    (Spectra,  5, Press, ROTATE_RIGHT),
-    (Spectra,  6, Press, SHUFFLE),
-    (Spectra,  7, Press, SWAP),
-
-    (Spectra,  8, Press, ADVANCE),
-    (Spectra,  9, Press, BLASTER),
-    (Spectra, 10, Press, WAFFLE),
-
-    (Twister,  4, Press, CLEAR_TRANSIENT_X_TRANSLATION),
-    (Twister,  5, Press, CLEAR_TRANSIENT_Y_TRANSLATION),
-    (Twister,  6, Press, CLEAR_TRANSIENT_SCALE),
+    (SPECTRA,  0, Press, PUSH_TOP),
+    (SPECTRA,  1, Press, PUSH_BOTTOM),
+    (SPECTRA,  2, Press, CYCLE),
+    (SPECTRA,  3, Press, CYCLE_ZOOM),
+
+    (SPECTRA,  4, Press, ROTATE_LEFT),
+    (SPECTRA,  5, Press, ROTATE_RIGHT),
+    (SPECTRA,  6, Press, SHUFFLE),
+    (SPECTRA,  7, Press, SWAP),
+
+    (SPECTRA,  8, Press, ADVANCE),
+    (SPECTRA,  9, Press, BLASTER),
+    (SPECTRA, 10, Press, WAFFLE),
+
+    (TWISTER,  4, Press, CLEAR_TRANSIENT_X_TRANSLATION),
+    (TWISTER,  5, Press, CLEAR_TRANSIENT_Y_TRANSLATION),
+    (TWISTER,  6, Press, CLEAR_TRANSIENT_SCALE),
   ]
 };
 
@@ -36,116 +39,58 @@ const CHARACTER_BINDINGS: &[(ModeKind, char, ModifiersState, CommandEntry)] = {
 
 
   &[
-    (Normal, '+',  OFF,        INCREMENT_DB),
-    (Normal, '-',  OFF,        DECREMENT_DB),
-    (Normal, '.',  OFF,        REPEAT),
-    (Normal, ':',  OFF,        ENTER_COMMAND_MODE),
-    (Normal, '>',  OFF,        CAPTURE),
-    (Normal, '?',  OFF,        PRINT),
-    (Normal, 'A',  OFF,        ALL),
-    (Normal, 'B',  OFF,        BLASTER),
-    (Normal, 'B',  SUPER,      PRINT_BINDINGS),
-    (Normal, 'C',  OFF,        CIRCLE),
-    (Normal, 'D',  OFF,        COORDINATES),
-    (Normal, 'E',  OFF,        CLEAR_ELAPSED),
-    (Normal, 'F',  CTRL_SUPER, TOGGLE_FULLSCREEN),
-    (Normal, 'F',  OFF,        TOGGLE_FIT),
-    (Normal, 'I',  OFF,        TOGGLE_INTERPOLATE),
-    (Normal, 'L',  OFF,        FREQUENCIES),
-    (Normal, 'N',  OFF,        NONE),
-    (Normal, 'P',  CTRL,       ENTER_PLAY_MODE),
-    (Normal, 'P',  OFF,        PUSH_TOP),
-    (Normal, 'P',  SUPER,      TOGGLE_PAUSED),
-    (Normal, 'R',  OFF,        TOGGLE_REPEAT),
-    (Normal, 'R',  SHIFT,      RELOAD_SHADERS),
-    (Normal, 'S',  OFF,        SAMPLES),
-    (Normal, 'T',  OFF,        TOGGLE_TILE),
-    (Normal, 'U',  SUPER,      UNWIND),
-    (Normal, 'W',  OFF,        TOGGLE_WRAP),
-    (Normal, 'X',  OFF,        X),
-    (Normal, 'Z',  OFF,        ZOOM_OUT),
-    (Normal, 'Z',  SUPER,      UNDO),
-    (Normal, '\\', SUPER,      TOGGLE_MUTED),
-    (Play,   '1',  OFF,        SET_PATCH_SINE),
-    (Play,   '2',  OFF,        SET_PATCH_SAW),
+    (Normal, '+',  OFF,         INCREMENT_DB),
+    (Normal, '-',  OFF,         DECREMENT_DB),
+    (Normal, '.',  OFF,         REPEAT),
+    (Normal, ':',  OFF,         ENTER_COMMAND_MODE),
+    (Normal, '>',  OFF,         CAPTURE),
+    (Normal, '?',  OFF,         PRINT),
+    (Normal, 'A',  OFF,         ALL),
+    (Normal, 'B',  OFF,         BLASTER),
+    (Normal, 'B',  SUPER,       PRINT_BINDINGS),
+    (Normal, 'C',  OFF,         CIRCLE),
+    (Normal, 'D',  OFF,         COORDINATES),
+    (Normal, 'E',  OFF,         CLEAR_ELAPSED),
+    (Normal, 'F',  CTRL_SUPER,  TOGGLE_FULLSCREEN),
+    (Normal, 'F',  OFF,         TOGGLE_FIT),
+    (Normal, 'I',  OFF,         TOGGLE_INTERPOLATE),
+    (Normal, 'L',  OFF,         FREQUENCIES),
+    (Normal, 'N',  OFF,         NONE),
+    (Normal, 'P',  CTRL,        ENTER_PLAY_MODE),
+    (Normal, 'P',  OFF,         PUSH_TOP),
+    (Normal, 'P',  SUPER,       TOGGLE_PAUSED),
+    (Normal, 'R',  OFF,         TOGGLE_REPEAT),
+    (Normal, 'R',  SHIFT,       RELOAD_SHADERS),
+    (Normal, 'S',  OFF,         SAMPLES),
+    (Normal, 'S',  SUPER,       SAVE_SCENE),
+    (Normal, 'T',  OFF,         TOGGLE_TILE),
+    (Normal, 'U',  SUPER,       UNWIND),
+    (Normal, 'W',  OFF,         TOGGLE_WRAP),
+    (Normal, 'X',  OFF,         X),
+    (Normal, 'Z',  OFF,         ZOOM_OUT),
+    (Normal, 'Z',  SHIFT_SUPER, REDO),
+    (Normal, 'Z',  SUPER,       UNDO),
+    (Normal, '[',  OFF,         NUDGE_EARLIER),
+    (Normal, '\\', SUPER,       TOGGLE_MUTED),
+    (Normal, ']',  OFF,         NUDGE_LATER),
+    (Normal, '{',  OFF,         HALF_TEMPO),
+    (Normal, '}',  OFF,         DOUBLE_TEMPO),
+    (Play,   '1',  OFF,         SET_PATCH_SINE),
+    (Play,   '2',  OFF,         SET_PATCH_SAW),
   ]
 };
 
-const ENCODER_BINDINGS: &[(Controller, u8, (&str, fn(&mut State, u7) -> f32))] = {
-  use Controller::*;
-
-  fn integer(value: u7) -> f32 {
-    let value = i8::try_from(u8::from(value)).unwrap() - 64;
-    if value == -1 { 0.0 } else { value as f32 }
-  }
-
-  fn float(value: u7) -> f32 {
-    let value = integer(value);
-    if value < 0.0 {
-      value / 64.0
-    } else {
-      value / 63.0
-    }
-  }
-
-  fn set_alpha(state: &mut State, value: u7) -> f32 {
-    let value = float(value).midpoint(1.0);
-    state.alpha = value;
-    value
-  }
-
-  fn set_complexity(state: &mut State, value: u7) -> f32 {
-    let value = float(value);
-    state.complexity = value;
-    value
-  }
-
-  fn set_db(state: &mut State, value: u7) -> f32 {
-    let value = integer(value);
-    state.db = value;
-    value
-  }
-
-  fn set_velocity_x(state: &mut State, value: u7) -> f32 {
-    let value = float(value);
-    state.velocity.x = value;
-    value
-  }
-
-  fn set_velocity_y(state: &mut State, value: u7) -> f32 {
-    let value = float(value);
-    state.velocity.y = value;
-    value
-  }
-
-  fn set_velocity_scaling(state: &mut State, value: u7) -> f32 {
-    let value = float(value);
-    state.velocity.z = value;
-    value
-  }
-
-  fn set_velocity_rotation(state: &mut State, value: u7) -> f32 {
-    let value = float(value);
-    state.velocity.w = value;
-    value
-  }
-
-  macro_rules! command {
-    {
-      $name:ident
-    } => {
-      (stringify!($name), $name)
-    }
-  }
+const ENCODER_BINDINGS: &[(Controller, u8, Parameter)] = {
+  const TWISTER: Controller = Controller::TWISTER;
 
   &[
-    (Twister, 0, command!(set_alpha)),
-    (Twister, 1, command!(set_db)),
-    (Twister, 4, command!(set_velocity_x)),
-    (Twister, 5, command!(set_velocity_y)),
-    (Twister, 6, command!(set_velocity_scaling)),
-    (Twister, 7, command!(set_velocity_rotation)),
-    (Twister, 8, command!(set_complexity)),
+    (TWISTER, 0, Parameter::Alpha),
+    (TWISTER, 1, Parameter::Db),
+    (TWISTER, 4, Parameter::VelocityX),
+    (TWISTER, 5, Parameter::VelocityY),
+    (TWISTER, 6, Parameter::VelocityScaling),
+    (TWISTER, 7, Parameter::VelocityRotation),
+    (TWISTER, 8, Parameter::Complexity),
   ]
 };
 
@@ -165,6 +110,7 @@ const NAMED_BINDINGS: &[(ModeKind, NamedKey, ModifiersState, CommandEntry)] = {
     (Normal,  ArrowLeft,  OFF, NEGATIVE_ROTATION),
     (Normal,  ArrowRight, OFF, POSITIVE_ROTATION),
     (Normal,  Backspace,  OFF, POP),
+    (Normal,  Space,      OFF, TAP_TEMPO),
     (Play,    Escape,     OFF, ENTER_NORMAL_MODE),
   ]
 };
@@ -172,40 +118,63 @@ const NAMED_BINDINGS: &[(ModeKind, NamedKey, ModifiersState, CommandEntry)] = {
 pub(crate) struct Bindings {
   button: BTreeMap<(Controller, u8, Press), CommandEntry>,
   character: BTreeMap<(ModeKind, String, ModifiersState), CommandEntry>,
-  encoder: BTreeMap<(Controller, u8), (&'static str, fn(&mut State, u7) -> f32)>,
+  encoder: BTreeMap<(Controller, u8), Parameter>,
   named: BTreeMap<(ModeKind, NamedKey, ModifiersState), CommandEntry>,
+  onset: BTreeMap<Band, CommandEntry>,
 }
 
 impl Bindings {
-  pub(crate) fn button(&self, controller: Controller, button: u8, press: Press) -> Option<Command> {
-    let entry = self.button.get(&(controller, button, press)).copied();
+  pub(crate) fn button(
+    &self,
+    controller: &Controller,
+    button: u8,
+    press: Press,
+  ) -> Option<CommandEntry> {
+    let entry = self
+      .button
+      .get(&(controller.clone(), button, press))
+      .copied();
 
     if let Some(entry) = &entry {
       eprintln!("{}", entry.name);
     } else {
-      log::info!("unbound button: {controller:?} {button} {press:?}");
+      log::info!("unbound button: {controller} {button} {press:?}");
     }
 
-    entry.map(|entry| entry.command)
+    entry
   }
 
-  pub(crate) fn encoder(
-    &self,
-    controller: Controller,
-    encoder: u8,
-  ) -> Option<fn(&mut State, u7) -> f32> {
-    let entry = self.encoder.get(&(controller, encoder)).copied();
+  pub(crate) fn buttons(&self) -> BTreeMap<(&Controller, u8), Vec<CommandEntry>> {
+    let mut buttons = BTreeMap::<_, Vec<CommandEntry>>::new();
 
-    if let Some(entry) = &entry {
-      eprintln!("{}", entry.0);
+    for ((controller, control, _press), entry) in &self.button {
+      buttons
+        .entry((controller, *control))
+        .or_default()
+        .push(*entry);
+    }
+
+    buttons
+  }
+
+  pub(crate) fn encoder(&self, controller: &Controller, encoder: u8) -> Option<Parameter> {
+    let parameter = self.encoder.get(&(controller.clone(), encoder)).copied();
+
+    if let Some(parameter) = parameter {
+      eprintln!("{parameter}");
     } else {
-      log::info!("unbound encoder: {controller:?} {encoder}");
+      log::info!("unbound encoder: {controller} {encoder}");
     }
 
-    entry.map(|entry| entry.1)
+    parameter
   }
 
-  pub(crate) fn key(&self, mode: ModeKind, key: &Key, modifiers: Modifiers) -> Option<Command> {
+  pub(crate) fn key(
+    &self,
+    mode: ModeKind,
+    key: &Key,
+    modifiers: Modifiers,
+  ) -> Option<CommandEntry> {
     let entry = match key {
       Key::Character(character) => {
         let character = character.to_uppercase();
@@ -227,14 +196,38 @@ impl Bindings {
       log::info!("unbound key: {key:?} {modifiers:?}");
     }
 
-    entry.map(|entry| entry.command)
+    entry
+  }
+
+  fn label(modifiers: ModifiersState, key: &str) -> String {
+    let mut label = Vec::new();
+
+    if modifiers.control_key() {
+      label.push("⌃");
+    }
+
+    if modifiers.alt_key() {
+      label.push("⌥");
+    }
+
+    if modifiers.shift_key() {
+      label.push("⇧");
+    }
+
+    if modifiers.super_key() {
+      label.push("⌘");
+    }
+
+    label.push(key);
+
+    label.join(" ")
   }
 
-  pub(crate) fn new() -> Self {
-    Self {
+  pub(crate) fn new(config: &BindingConfig) -> Result<Self> {
+    let mut bindings = Self {
       button: BUTTON_BINDINGS
         .iter()
-        .copied()
+        .cloned()
         .map(|(controller, control, pressed, command)| ((controller, control, pressed), command))
         .collect(),
       character: CHARACTER_BINDINGS
@@ -245,49 +238,152 @@ impl Bindings {
         .collect(),
       encoder: ENCODER_BINDINGS
         .iter()
-        .copied()
-        .map(|(controller, control, command)| ((controller, control), command))
+        .cloned()
+        .map(|(controller, control, parameter)| ((controller, control), parameter))
         .collect(),
       named: NAMED_BINDINGS
         .iter()
         .copied()
         .map(|(mode, named, modifiers, command)| ((mode, named, modifiers), command))
         .collect(),
-    }
-  }
-}
+      onset: BTreeMap::new(),
+    };
 
-impl Display for Bindings {
-  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-    use tabled::{
-      builder::Builder,
-      settings::{Alignment, Panel, Span, Style, object::Columns, themes::BorderCorrection},
+    let commands = Commands::new();
+
+    let command = |name: &str| {
+      let entry = commands.name(name).context(error::BindingCommand {
+        command: name.to_owned(),
+      })?;
+
+      ensure! {
+        !entry.command.takes_arguments(),
+        error::BindingArguments {
+          command: name.to_owned(),
+        },
+      }
+
+      Ok(entry)
     };
 
-    fn binding(modifiers: ModifiersState, key: &str) -> String {
-      let mut binding = Vec::new();
+    let mut buttons = HashSet::new();
 
-      if modifiers.control_key() {
-        binding.push("⌃");
+    for binding in &config.buttons {
+      let key = (binding.controller.clone(), binding.control, binding.press);
+
+      ensure! {
+        buttons.insert(key.clone()),
+        error::BindingConflict {
+          binding: format!(
+            "{} {} {:?}",
+            binding.controller.name(),
+            binding.control,
+            binding.press,
+          ),
+        },
       }
 
-      if modifiers.alt_key() {
-        binding.push("⌥");
+      bindings.button.insert(key, command(&binding.command)?);
+    }
+
+    let mut encoders = HashSet::new();
+
+    for binding in &config.encoders {
+      let key = (binding.controller.clone(), binding.control);
+
+      ensure! {
+        encoders.insert(key.clone()),
+        error::BindingConflict {
+          binding: format!("{} {} encoder", binding.controller.name(), binding.control),
+        },
       }
 
-      if modifiers.shift_key() {
-        binding.push("⇧");
+      bindings.encoder.insert(key, binding.parameter);
+    }
+
+    let mut keys = HashSet::new();
+
+    for binding in &config.keys {
+      let mut modifiers = binding.modifiers();
+
+      let label = match &binding.key {
+        BindingKey::Character(character) => {
+          let character = character.to_uppercase().to_string();
+
+          if character == character.to_lowercase() {
+            modifiers.remove(ModifiersState::SHIFT);
+          }
+
+          character
+        }
+        BindingKey::Named(named) => format!("{named:?}"),
+      };
+
+      ensure! {
+        keys.insert((binding.mode, label.clone(), modifiers)),
+        error::BindingConflict {
+          binding: format!(
+            "{} in {} mode",
+            Self::label(modifiers, &label),
+            binding.mode.name(),
+          ),
+        },
       }
 
-      if modifiers.super_key() {
-        binding.push("⌘");
+      let entry = command(&binding.command)?;
+
+      match binding.key {
+        BindingKey::Character(_) => {
+          bindings
+            .character
+            .insert((binding.mode, label, modifiers), entry);
+        }
+        BindingKey::Named(named) => {
+          bindings
+            .named
+            .insert((binding.mode, named, modifiers), entry);
+        }
       }
+    }
 
-      binding.push(key);
+    for binding in &config.onsets {
+      ensure! {
+        !bindings.onset.contains_key(&binding.band),
+        error::BindingConflict {
+          binding: format!("{} onset", binding.band.name()),
+        },
+      }
 
-      binding.join(" ")
+      bindings
+        .onset
+        .insert(binding.band, command(&binding.command)?);
     }
 
+    Ok(bindings)
+  }
+
+  pub(crate) fn onset(&self, band: Band) -> Option<CommandEntry> {
+    self.onset.get(&band).copied()
+  }
+
+  pub(crate) fn rings<'a>(
+    &'a self,
+    state: &'a State,
+  ) -> impl Iterator<Item = (&'a Controller, u8, u7)> + 'a {
+    self
+      .encoder
+      .iter()
+      .map(|((controller, control), parameter)| (controller, *control, parameter.get(state)))
+  }
+}
+
+impl Display for Bindings {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    use tabled::{
+      builder::Builder,
+      settings::{Alignment, Panel, Span, Style, object::Columns, themes::BorderCorrection},
+    };
+
     {
       let mut modes = BTreeMap::<ModeKind, Vec<[String; 2]>>::new();
 
@@ -295,12 +391,12 @@ impl Display for Bindings {
         modes
           .entry(*mode)
           .or_default()
-          .push([binding(*modifiers, character), (*name).into()]);
+          .push([Self::label(*modifiers, character), (*name).into()]);
       }
 
       for ((mode, named_key, modifiers), CommandEntry { name, .. }) in &self.named {
         modes.entry(*mode).or_default().push([
-          binding(*modifiers, &format!("{named_key:?}")),
+          Self::label(*modifiers, &format!("{named_key:?}")),
           (*name).into(),
         ]);
       }
@@ -335,7 +431,7 @@ impl Display for Bindings {
     {
       let mut spectra = Vec::new();
       for ((controller, control, _press), CommandEntry { name, .. }) in &self.button {
-        if *controller != Controller::Spectra {
+        if *controller != Controller::SPECTRA {
           continue;
         }
         let i = control.into_usize();
@@ -354,20 +450,20 @@ impl Display for Bindings {
         builder
           .build()
           .with(Style::modern())
-          .with(Panel::header(Controller::Spectra.name()))
+          .with(Panel::header(Controller::SPECTRA.name()))
           .with(BorderCorrection::span())
       )?;
     }
 
     {
       let mut twister = Vec::new();
-      for ((controller, control), (name, _command)) in &self.encoder {
-        if *controller != Controller::Twister {
+      for ((controller, control), parameter) in &self.encoder {
+        if *controller != Controller::TWISTER {
           continue;
         }
         let i = control.into_usize();
         twister.resize(i + 1, "");
-        twister[i] = name;
+        twister[i] = parameter.path();
       }
 
       let mut builder = Builder::default();
@@ -381,7 +477,46 @@ impl Display for Bindings {
         builder
           .build()
           .with(Style::modern())
-          .with(Panel::header(Controller::Twister.name()))
+          .with(Panel::header(Controller::TWISTER.name()))
+          .with(BorderCorrection::span())
+      )?;
+    }
+
+    {
+      let mut builder = Builder::default();
+      for ((controller, control), parameter) in &self.encoder {
+        if *controller == Controller::TWISTER {
+          continue;
+        }
+        builder.push_record([controller.name(), &control.to_string(), parameter.path()]);
+      }
+
+      if builder.count_records() > 0 {
+        write!(
+          f,
+          "\n{}",
+          builder
+            .build()
+            .with(Style::modern())
+            .with(Panel::header("encoders"))
+            .with(BorderCorrection::span())
+        )?;
+      }
+    }
+
+    if !self.onset.is_empty() {
+      let mut builder = Builder::default();
+      for (band, CommandEntry { name, .. }) in &self.onset {
+        builder.push_record([band.name(), name]);
+      }
+
+      write!(
+        f,
+        "\n{}",
+        builder
+          .build()
+          .with(Style::modern())
+          .with(Panel::header("onsets"))
           .with(BorderCorrection::span())
       )?;
     }
@@ -418,10 +553,136 @@ mod tests {
     }
   }
 
+  #[test]
+  fn config_bindings() {
+    #[track_caller]
+    fn error(yaml: &str, expected: &str) {
+      assert_eq!(new(yaml).err().unwrap().to_string(), expected);
+    }
+
+    #[track_caller]
+    fn new(yaml: &str) -> Result<Bindings> {
+      Bindings::new(&serde_yaml::from_str(yaml).unwrap())
+    }
+
+    let bindings = new(
+      "
+keys:
+- mode: normal
+  key: A
+  command: toggle_fit
+- mode: normal
+  key: '!'
+  modifiers: [shift]
+  command: undo
+- mode: command
+  key: ArrowUp
+  command: complete-command
+buttons:
+- controller: spectra
+  control: 0
+  command: shuffle
+encoders:
+- controller: twister
+  control: 0
+  parameter: velocity/x
+- controller: launch-control
+  control: 77
+  parameter: db
+onsets:
+- band: kick
+  command: push_top
+",
+    )
+    .unwrap();
+
+    assert_eq!(
+      bindings.character[&(ModeKind::Normal, "A".into(), OFF)].name,
+      "toggle-fit",
+    );
+
+    assert_eq!(
+      bindings.character[&(ModeKind::Normal, "!".into(), OFF)].name,
+      "undo",
+    );
+
+    assert_eq!(
+      bindings.character[&(ModeKind::Normal, "B".into(), OFF)].name,
+      "blaster",
+    );
+
+    assert_eq!(
+      bindings.named[&(ModeKind::Command, NamedKey::ArrowUp, OFF)].name,
+      "complete-command",
+    );
+
+    assert_eq!(
+      bindings.button[&(Controller::SPECTRA, 0, Press::Press)].name,
+      "shuffle",
+    );
+
+    assert_eq!(
+      bindings.encoder(&Controller::TWISTER, 0),
+      Some(Parameter::VelocityX),
+    );
+
+    assert_eq!(
+      bindings.encoder(&Controller::TWISTER, 1),
+      Some(Parameter::Db),
+    );
+
+    assert_eq!(
+      bindings.encoder(&serde_yaml::from_str("launch-control").unwrap(), 77),
+      Some(Parameter::Db),
+    );
+
+    assert_eq!(bindings.onset(Band::Kick).unwrap().name, "push-top");
+
+    assert!(bindings.onset(Band::Hats).is_none());
+
+    error(
+      "keys: [{mode: normal, key: a, command: foo}]",
+      "unknown command `foo` in bindings",
+    );
+
+    error(
+      "keys: [{mode: normal, key: a, command: db}]",
+      "command `db` in bindings takes arguments",
+    );
+
+    error(
+      "keys: [{mode: normal, key: a, command: all}, {mode: normal, key: A, command: none}]",
+      "conflicting bindings for A in Normal mode",
+    );
+
+    error(
+      "keys: [{mode: play, key: '1', modifiers: [shift], command: all}, \
+      {mode: play, key: '1', command: none}]",
+      "conflicting bindings for 1 in Play mode",
+    );
+
+    error(
+      "buttons: [{controller: twister, control: 1, command: all}, \
+      {controller: twister, control: 1, press: press, command: none}]",
+      "conflicting bindings for twister 1 Press",
+    );
+
+    error(
+      "encoders: [{controller: twister, control: 1, parameter: db}, \
+      {controller: twister, control: 1, parameter: alpha}]",
+      "conflicting bindings for twister 1 encoder",
+    );
+
+    error(
+      "onsets: [{band: kick, command: all}, {band: kick, command: none}]",
+      "conflicting bindings for kick onset",
+    );
+  }
+
   #[test]
   fn encoder_bindings_are_unique() {
     let mut encoders = HashSet::new();
-    for (controller, control, _command) in ENCODER_BINDINGS {
+    for (controller, control, _parameter) in ENCODER_BINDINGS {
       assert!(encoders.insert((controller, control)));
     }
   }
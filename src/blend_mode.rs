@@ -1,7 +1,9 @@
 use super::*;
 
-#[derive(Clone, Copy, Debug, Default, EnumIter, IntoStaticStr)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, IntoStaticStr, Serialize)]
+#[allow(clippy::unsafe_derive_deserialize)]
 #[repr(u32)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum BlendMode {
   #[default]
   Destination,
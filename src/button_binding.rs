@@ -0,0 +1,11 @@
+use super::*;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ButtonBinding {
+  pub(crate) command: String,
+  pub(crate) control: u8,
+  pub(crate) controller: Controller,
+  #[serde(default)]
+  pub(crate) press: Press,
+}
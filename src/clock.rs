@@ -0,0 +1,145 @@
+use super::*;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Clock {
+  armed: bool,
+  intervals: VecDeque<u64>,
+  last: Option<u64>,
+  pulses: Option<u64>,
+  running: bool,
+}
+
+impl Clock {
+  const INTERVALS: usize = 24;
+  const PULSES_PER_BEAT: u64 = 24;
+  const PULSES_PER_QUARTER: u64 = 6;
+
+  pub(crate) fn active(&self) -> bool {
+    self.running || self.pulses.is_some()
+  }
+
+  fn beats(&self) -> f64 {
+    self.pulses.unwrap_or_default() as f64 / Self::PULSES_PER_BEAT as f64
+  }
+
+  pub(crate) fn bpm(&self) -> Option<f64> {
+    if self.intervals.is_empty() {
+      return None;
+    }
+
+    let interval = self.intervals.iter().sum::<u64>() as f64 / self.intervals.len() as f64;
+
+    if interval == 0.0 {
+      return None;
+    }
+
+    Some(60_000_000.0 / (interval * Self::PULSES_PER_BEAT as f64))
+  }
+
+  pub(crate) fn position(&self) -> Option<Position> {
+    Some(Position::from_quarter(
+      self.pulses? / Self::PULSES_PER_QUARTER,
+    ))
+  }
+
+  pub(crate) fn tempo(&self, time: f64) -> Option<Tempo> {
+    let bpm = self.bpm()?;
+
+    Some(Tempo {
+      bpm,
+      offset: time - self.beats() * 60.0 / bpm,
+    })
+  }
+
+  pub(crate) fn update(&mut self, timestamp: u64, transport: Transport) {
+    match transport {
+      Transport::Clock => {
+        if let Some(last) = self.last {
+          if self.intervals.len() == Self::INTERVALS {
+            self.intervals.pop_front();
+          }
+          self.intervals.push_back(timestamp.saturating_sub(last));
+        }
+
+        self.last = Some(timestamp);
+
+        if self.running {
+          if self.armed {
+            self.armed = false;
+          } else if let Some(pulses) = &mut self.pulses {
+            *pulses += 1;
+          }
+        }
+      }
+      Transport::Continue => {
+        self.armed = true;
+        self.last = None;
+        self.pulses.get_or_insert_default();
+        self.running = true;
+      }
+      Transport::SongPosition(position) => {
+        self.pulses = Some(u64::from(position) * Self::PULSES_PER_QUARTER);
+      }
+      Transport::Start => {
+        self.armed = true;
+        self.last = None;
+        self.pulses = Some(0);
+        self.running = true;
+      }
+      Transport::Stop => {
+        self.running = false;
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, assert_float_eq::assert_float_absolute_eq};
+
+  fn pulses(clock: &mut Clock, timestamp: &mut u64, count: u64) {
+    for _ in 0..count {
+      clock.update(*timestamp, Transport::Clock);
+      *timestamp += 20_833;
+    }
+  }
+
+  #[test]
+  fn song_position() {
+    let mut clock = Clock::default();
+    let mut timestamp = 0;
+
+    clock.update(timestamp, Transport::SongPosition(16));
+    assert_eq!(clock.position(), Some(Position::from_bar(1)));
+
+    clock.update(timestamp, Transport::Continue);
+    pulses(&mut clock, &mut timestamp, 7);
+    assert_eq!(clock.position(), Some(Position::from_quarter(17)));
+
+    clock.update(timestamp, Transport::Stop);
+    pulses(&mut clock, &mut timestamp, 24);
+    assert_eq!(clock.position(), Some(Position::from_quarter(17)));
+  }
+
+  #[test]
+  fn start() {
+    let mut clock = Clock::default();
+    let mut timestamp = 0;
+
+    pulses(&mut clock, &mut timestamp, 24);
+    assert_eq!(clock.position(), None);
+    assert!(!clock.active());
+    assert_float_absolute_eq!(clock.bpm().unwrap(), 120.0, 0.01);
+
+    clock.update(timestamp, Transport::Start);
+    assert_eq!(clock.position(), Some(Position::from_quarter(0)));
+    assert!(clock.active());
+
+    pulses(&mut clock, &mut timestamp, 97);
+    assert_eq!(clock.position(), Some(Position::from_bar(1)));
+
+    let tempo = clock.tempo(10.0).unwrap();
+    assert_float_absolute_eq!(tempo.bpm, 120.0, 0.01);
+    assert_float_absolute_eq!(tempo.beats(10.0), 4.0, 0.001);
+  }
+}
@@ -8,5 +8,22 @@ pub(crate) enum Command {
   History(fn(&mut History)),
   HistoryState(fn(&mut History, &mut State)),
   RngState(fn(&mut SmallRng, &mut State)),
+  RngStateArguments(fn(&mut SmallRng, &mut State, &[String]) -> Result<(), ArgumentError>),
   State(fn(&mut State)),
+  StateArguments(fn(&mut State, &[String]) -> Result<(), ArgumentError>),
+  Tap(fn(&mut Tap)),
+  TapArguments(fn(&mut Tap, &[String]) -> Result<(), ArgumentError>),
+}
+
+impl Command {
+  pub(crate) fn is_tap(self) -> bool {
+    matches!(self, Self::Tap(_) | Self::TapArguments(_))
+  }
+
+  pub(crate) fn takes_arguments(self) -> bool {
+    matches!(
+      self,
+      Self::RngStateArguments(_) | Self::StateArguments(_) | Self::TapArguments(_)
+    )
+  }
 }
@@ -13,8 +13,18 @@ impl Commands {
       .and_then(|(name, _command)| name.strip_prefix(prefix))
   }
 
-  pub(crate) fn name(&self, s: &str) -> Option<Command> {
-    self.entries.get(s).copied()
+  pub(crate) fn entries(&self) -> impl Iterator<Item = CommandEntry> {
+    self
+      .entries
+      .iter()
+      .map(|(name, command)| CommandEntry::new(name, *command))
+  }
+
+  pub(crate) fn name(&self, s: &str) -> Option<CommandEntry> {
+    self
+      .entries
+      .get_key_value(s.replace('_', "-").as_str())
+      .map(|(name, command)| CommandEntry::new(name, *command))
   }
 
   pub(crate) fn new() -> Self {
@@ -59,6 +69,14 @@ pub(crate) fn all(state: &mut State) {
   });
 }
 
+pub(crate) fn alpha(state: &mut State, alpha: f32) {
+  state.alpha = alpha;
+}
+
+pub(crate) fn band(state: &mut State, band: Band) {
+  state.band(band);
+}
+
 pub(crate) fn blaster(rng: &mut SmallRng, state: &mut State) {
   let presets = (0..state.preset_limit())
     .map(|i| Preset::random(rng, i))
@@ -93,7 +111,7 @@ pub(crate) fn bottom(state: &mut State) {
 }
 
 pub(crate) fn capture(app: &mut App) -> Result {
-  let destination = app.config.capture(None, "png");
+  let destination = app.config.capture(None, Some("png"));
   app.renderer.as_ref().unwrap().capture(move |capture| {
     if let Err(err) = capture.save(&destination) {
       log::error!("failed to send capture: {err}");
@@ -139,6 +157,9 @@ pub(crate) fn complete_command(app: &mut App) {
     return;
   };
   let prefix = command.iter().flat_map(|c| c.chars()).collect::<String>();
+  if prefix.contains(char::is_whitespace) {
+    return;
+  }
   if let Some(suffix) = app.commands.complete(&prefix) {
     if !suffix.is_empty() {
       eprintln!("completion: {prefix}{suffix}");
@@ -197,10 +218,18 @@ pub(crate) fn cycle_zoom(state: &mut State) {
   state.count += 1;
 }
 
+pub(crate) fn db(state: &mut State, db: f32) {
+  state.db = db;
+}
+
 pub(crate) fn decrement_db(state: &mut State) {
   state.db -= 1.0;
 }
 
+pub(crate) fn double_tempo(tap: &mut Tap) {
+  tap.scale_tempo(2.0);
+}
+
 pub(crate) fn enter_command_mode(app: &mut App) {
   app.mode = Mode::Command(Vec::new());
 }
@@ -218,13 +247,14 @@ pub(crate) fn execute_command(app: &mut App, event_loop: &ActiveEventLoop) {
     return;
   };
   let command = command.iter().flat_map(|c| c.chars()).collect::<String>();
-  if let Some(resolved) = app.commands.name(command.as_str()) {
-    eprintln!("{command}");
-    app.dispatch(event_loop, resolved);
-  } else {
-    eprintln!("unknown command: {command}");
-  }
   app.mode = Mode::Normal;
+  let mut words = command.split_whitespace();
+  let Some(name) = words.next() else {
+    return;
+  };
+  let arguments = words.map(str::to_owned).collect::<Vec<String>>();
+  eprintln!("{command}");
+  app.execute(event_loop, name, &arguments);
 }
 
 pub(crate) fn frequencies(state: &mut State) {
@@ -236,6 +266,19 @@ pub(crate) fn frequencies(state: &mut State) {
   });
 }
 
+pub(crate) fn goniometer(state: &mut State) {
+  state.filters.push(Filter {
+    color: color::invert(),
+    field: Field::Goniometer,
+    wrap: state.wrap,
+    ..default()
+  });
+}
+
+pub(crate) fn half_tempo(tap: &mut Tap) {
+  tap.scale_tempo(0.5);
+}
+
 pub(crate) fn increment_db(state: &mut State) {
   state.db += 1.0;
 }
@@ -249,6 +292,12 @@ pub(crate) fn left(state: &mut State) {
   });
 }
 
+pub(crate) fn r#loop(tap: &mut Tap, start: Position, end: Position) {
+  if let Err(err) = tap.seek(start, Some(end)) {
+    eprintln!("{err}");
+  }
+}
+
 pub(crate) fn negative_rotation(state: &mut State) {
   state.filters.push(Filter {
     position: Mat3f::new_rotation(-0.01),
@@ -264,6 +313,10 @@ pub(crate) fn negative_x_translation(state: &mut State) {
   });
 }
 
+pub(crate) fn next_track(tap: &mut Tap) {
+  tap.next_track();
+}
+
 pub(crate) fn none(state: &mut State) {
   state.filters.push(Filter {
     field: Field::None,
@@ -272,6 +325,14 @@ pub(crate) fn none(state: &mut State) {
   });
 }
 
+pub(crate) fn nudge_earlier(tap: &mut Tap) {
+  tap.nudge(-NUDGE);
+}
+
+pub(crate) fn nudge_later(tap: &mut Tap) {
+  tap.nudge(NUDGE);
+}
+
 pub(crate) fn pop(state: &mut State) {
   state.pop();
 }
@@ -302,6 +363,14 @@ pub(crate) fn positive_x_translation(state: &mut State) {
   });
 }
 
+pub(crate) fn preset(rng: &mut SmallRng, state: &mut State, preset: Preset) {
+  state.filters.push(preset.filter(rng));
+}
+
+pub(crate) fn previous_track(tap: &mut Tap) {
+  tap.previous_track();
+}
+
 pub(crate) fn print(app: &mut App) {
   let allocated = Allocator::allocated();
 
@@ -327,6 +396,26 @@ pub(crate) fn print_bindings(app: &mut App) {
   eprintln!("{}", app.bindings);
 }
 
+pub(crate) fn print_history(app: &mut App) {
+  for invocation in &app.history.commands {
+    eprintln!("{invocation}");
+  }
+
+  eprintln!(
+    "undo: {} redo: {}",
+    app.history.states.len(),
+    app.history.redo.len(),
+  );
+}
+
+pub(crate) fn print_tempo(app: &mut App) {
+  if let Some(Tempo { bpm, offset }) = app.tap.tempo() {
+    eprintln!("tempo: {bpm:.2} bpm at {offset:.3}s");
+  } else {
+    eprintln!("tempo: none");
+  }
+}
+
 pub(crate) fn push_bottom(rng: &mut SmallRng, state: &mut State) {
   state
     .filters
@@ -339,6 +428,10 @@ pub(crate) fn push_top(rng: &mut SmallRng, state: &mut State) {
     .push(Preset::random(rng, state.filters.len()).filter(rng));
 }
 
+pub(crate) fn redo(history: &mut History, state: &mut State) {
+  history.redo(state);
+}
+
 pub(crate) fn reload_shaders(app: &mut App) {
   if let Err(err) = app.renderer.as_mut().unwrap().reload_shaders() {
     eprintln!("failed to reload shader: {err}");
@@ -346,10 +439,45 @@ pub(crate) fn reload_shaders(app: &mut App) {
 }
 
 pub(crate) fn repeat(app: &mut App, event_loop: &ActiveEventLoop) {
-  let Some(last) = app.history.commands.last() else {
+  let Some(Invocation {
+    arguments, entry, ..
+  }) = app.history.commands.back().cloned()
+  else {
     return;
   };
-  app.dispatch(event_loop, *last);
+  app.dispatch(event_loop, entry, &arguments);
+}
+
+pub(crate) fn replay(app: &mut App) {
+  if app.history.truncated {
+    eprintln!("failed to replay: history truncated");
+    return;
+  }
+
+  let mut rng = SmallRng::seed_from_u64(app.seed);
+
+  let mut state = match app.options.state(&app.config, &mut rng) {
+    Ok(state) => state,
+    Err(err) => {
+      eprintln!("failed to replay: {err}");
+      return;
+    }
+  };
+
+  match History::replay(
+    &app.history.commands,
+    app.options.fps.unwrap_or(DEFAULT_FPS.into()).dt(),
+    app.elapsed,
+    &mut rng,
+    &mut state,
+  ) {
+    Ok(history) => {
+      app.history = history;
+      app.rng = rng;
+      app.state = state;
+    }
+    Err(err) => eprintln!("failed to replay: {err}"),
+  }
 }
 
 pub(crate) fn right(state: &mut State) {
@@ -361,6 +489,10 @@ pub(crate) fn right(state: &mut State) {
   });
 }
 
+pub(crate) fn rms(state: &mut State) {
+  state.source(Source::Rms);
+}
+
 pub(crate) fn rotate_left(state: &mut State) {
   state.filters.rotate_left(1);
 }
@@ -378,6 +510,43 @@ pub(crate) fn samples(state: &mut State) {
   });
 }
 
+pub(crate) fn save_scene(app: &mut App) {
+  let destination = app.config.capture(None, Some("yaml"));
+
+  if app.state.callback.is_some() {
+    log::warn!("scene callback will not be saved");
+  }
+
+  if app
+    .state
+    .filters
+    .iter()
+    .any(|filter| filter.media.is_some())
+  {
+    log::warn!("filter media will not be saved");
+  }
+
+  if let Err(err) = app.state.save(&destination) {
+    eprintln!("failed to save scene: {err}");
+  } else {
+    eprintln!("saved scene to {destination}");
+  }
+}
+
+pub(crate) fn scene(rng: &mut SmallRng, state: &mut State, scene: Scene) {
+  *state = scene.state(rng);
+}
+
+pub(crate) fn seek(tap: &mut Tap, position: Position) {
+  if let Err(err) = tap.seek(position, None) {
+    eprintln!("{err}");
+  }
+}
+
+pub(crate) fn set(state: &mut State, parameter: Parameter, value: f32) {
+  parameter.set(state, value);
+}
+
 pub(crate) fn set_patch_saw(app: &mut App) {
   app.patch = Patch::Saw;
 }
@@ -390,6 +559,15 @@ pub(crate) fn shuffle(rng: &mut SmallRng, state: &mut State) {
   state.filters.shuffle(rng);
 }
 
+pub(crate) fn spectrogram(state: &mut State) {
+  state.filters.push(Filter {
+    color: color::invert(),
+    field: Field::Spectrogram,
+    wrap: state.wrap,
+    ..default()
+  });
+}
+
 pub(crate) fn spread(state: &mut State) {
   state.spread.toggle();
 }
@@ -407,6 +585,15 @@ pub(crate) fn status(state: &mut State) {
   state.status.toggle();
 }
 
+pub(crate) fn stereo(state: &mut State) {
+  state.filters.push(Filter {
+    color: color::invert(),
+    field: Field::Stereo,
+    wrap: state.wrap,
+    ..default()
+  });
+}
+
 pub(crate) fn swap(state: &mut State) {
   if state.filters.len() > 2 {
     let a = state.filters.pop().unwrap();
@@ -416,6 +603,10 @@ pub(crate) fn swap(state: &mut State) {
   }
 }
 
+pub(crate) fn tap_tempo(tap: &mut Tap) {
+  tap.tap_tempo();
+}
+
 pub(crate) fn toggle_fit(state: &mut State) {
   state.viewport.toggle();
 }
@@ -431,12 +622,12 @@ pub(crate) fn toggle_interpolate(state: &mut State) {
   state.interpolate.toggle();
 }
 
-pub(crate) fn toggle_muted(app: &mut App) {
-  app.tap.toggle_muted();
+pub(crate) fn toggle_muted(tap: &mut Tap) {
+  tap.toggle_muted();
 }
 
-pub(crate) fn toggle_paused(app: &mut App) {
-  app.tap.toggle_paused();
+pub(crate) fn toggle_paused(tap: &mut Tap) {
+  tap.toggle_paused();
 }
 
 pub(crate) fn toggle_repeat(state: &mut State) {
@@ -470,20 +661,32 @@ pub(crate) fn triangle(state: &mut State) {
 }
 
 pub(crate) fn undo(history: &mut History, state: &mut State) {
-  if let Some(last) = history.states.pop() {
-    *state = last;
-  }
+  history.undo(state);
 }
 
 pub(crate) fn unwind(history: &mut History) {
   history.unwind = true;
 }
 
+pub(crate) fn velocity(state: &mut State, x: f32, y: f32, scaling: f32, rotation: f32) {
+  state.velocity = Vec4f::new(x, y, scaling, rotation);
+}
+
 pub(crate) fn waffle(history: &mut History, state: &mut State) {
-  if let Some(mut last) = history.states.pop() {
-    mem::swap(&mut last, state);
-    history.states.push(last);
-  }
+  history.waffle(state);
+}
+
+pub(crate) fn waveforms(state: &mut State) {
+  state.filters.push(Filter {
+    color: color::invert(),
+    field: Field::Waveforms,
+    wrap: state.wrap,
+    ..default()
+  });
+}
+
+pub(crate) fn width(state: &mut State) {
+  state.width();
 }
 
 pub(crate) fn x(state: &mut State) {
@@ -510,3 +713,57 @@ pub(crate) fn zoom_out(state: &mut State) {
     ..default()
   });
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn arguments() {
+    let Command::StateArguments(velocity) = generated::VELOCITY.command else {
+      panic!("velocity should take arguments");
+    };
+
+    let mut state = State::new();
+
+    velocity(
+      &mut state,
+      &["0.1".into(), "0".into(), "0".into(), "-2".into()],
+    )
+    .unwrap();
+
+    assert_eq!(state.velocity, Vec4f::new(0.1, 0.0, 0.0, -2.0));
+
+    assert_eq!(
+      velocity(&mut state, &["0.1".into()])
+        .unwrap_err()
+        .to_string(),
+      "expected 4 arguments but got 1",
+    );
+
+    assert_eq!(
+      velocity(
+        &mut state,
+        &["0".into(), "x".into(), "0".into(), "0".into()]
+      )
+      .unwrap_err()
+      .to_string(),
+      "invalid y `x`",
+    );
+
+    let Command::RngStateArguments(preset) = generated::PRESET.command else {
+      panic!("preset should take arguments");
+    };
+
+    preset(
+      &mut SmallRng::seed_from_u64(0),
+      &mut state,
+      &["spin".into()],
+    )
+    .unwrap();
+
+    assert_eq!(state.filters.last().unwrap().preset, Some(Preset::Spin));
+
+    assert!(!generated::TOGGLE_FIT.command.takes_arguments());
+  }
+}
@@ -2,13 +2,21 @@ use super::*;
 
 #[derive(Default, Deserialize)]
 pub(crate) struct Config {
+  #[serde(default)]
+  bindings: BindingConfig,
   captures: Option<Utf8PathBuf>,
+  #[serde(default)]
+  controllers: Vec<ControllerProfile>,
   images: Option<Utf8PathBuf>,
   music: Option<Utf8PathBuf>,
 }
 
 impl Config {
-  pub(crate) fn capture(&self, stem: Option<&str>, extension: &str) -> Utf8PathBuf {
+  pub(crate) fn bindings(&self) -> &BindingConfig {
+    &self.bindings
+  }
+
+  pub(crate) fn capture(&self, stem: Option<&str>, extension: Option<&str>) -> Utf8PathBuf {
     let stem = match stem {
       Some(stem) => stem.into(),
       None => SystemTime::now()
@@ -18,7 +26,10 @@ impl Config {
         .to_string(),
     };
 
-    let filename = format!("{stem}.{extension}");
+    let filename = match extension {
+      Some(extension) => format!("{stem}.{extension}"),
+      None => stem,
+    };
 
     if let Some(captures) = &self.captures {
       captures.join(filename)
@@ -27,13 +38,22 @@ impl Config {
     }
   }
 
-  fn find(dir: &Utf8Path, pattern: &str) -> Result<Utf8PathBuf> {
+  pub(crate) fn controllers(&self) -> &[ControllerProfile] {
+    &self.controllers
+  }
+
+  fn find(
+    dir: &Utf8Path,
+    pattern: &str,
+    supported: impl Fn(&Utf8Path) -> bool,
+  ) -> Result<Utf8PathBuf> {
     let regex = RegexBuilder::new(pattern)
       .case_insensitive(true)
       .build()
       .context(error::FindRegex)?;
 
     let mut matches = Vec::<Utf8PathBuf>::new();
+    let mut unsupported = Vec::<Utf8PathBuf>::new();
 
     for entry in WalkDir::new(dir) {
       let entry = entry.context(error::FindWalk)?;
@@ -50,8 +70,16 @@ impl Config {
         continue;
       };
 
-      if regex.is_match(haystack) {
-        matches.push(path.into_utf8_path()?.into());
+      if !regex.is_match(haystack) {
+        continue;
+      }
+
+      let path = path.into_utf8_path()?;
+
+      if supported(path) {
+        matches.push(path.into());
+      } else {
+        unsupported.push(path.into());
       }
     }
 
@@ -61,16 +89,25 @@ impl Config {
 
     match matches.into_iter().next() {
       Some(path) => Ok(path),
-      None => Err(error::FindMatch { pattern }.build()),
+      None if unsupported.is_empty() => Err(error::FindMatch { pattern }.build()),
+      None => Err(
+        error::FindUnsupported {
+          matches: unsupported,
+          pattern,
+        }
+        .build(),
+      ),
     }
   }
 
   pub(crate) fn find_image(&self, pattern: &str) -> Result<Utf8PathBuf> {
-    Self::find(self.images()?, pattern)
+    Self::find(self.images()?, pattern, |_| true)
   }
 
   pub(crate) fn find_song(&self, pattern: &str) -> Result<Utf8PathBuf> {
-    Self::find(self.music()?, pattern)
+    Self::find(self.music()?, pattern, |path| {
+      AudioFormat::from_path(path).is_some()
+    })
   }
 
   fn home() -> Result<Utf8PathBuf> {
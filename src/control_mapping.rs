@@ -0,0 +1,9 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ControlMapping {
+  pub(crate) channel: u8,
+  pub(crate) control: u8,
+  pub(crate) source: ControlSource,
+}
@@ -0,0 +1,12 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ControlSource {
+  Aftertouch(u8),
+  Cc(u8),
+  ChannelAftertouch,
+  Encoder(u8),
+  Note(u8),
+  PitchBend,
+}
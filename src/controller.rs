@@ -1,13 +1,20 @@
 use super::*;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, IntoStaticStr)]
-pub(crate) enum Controller {
-  Spectra,
-  Twister,
-}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[serde(transparent)]
+pub(crate) struct Controller(Cow<'static, str>);
 
 impl Controller {
-  pub(crate) fn name(self) -> &'static str {
-    self.into()
+  pub(crate) const SPECTRA: Self = Self(Cow::Borrowed("spectra"));
+  pub(crate) const TWISTER: Self = Self(Cow::Borrowed("twister"));
+
+  pub(crate) fn name(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for Controller {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
   }
 }
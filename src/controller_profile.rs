@@ -0,0 +1,182 @@
+use super::*;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ControllerProfile {
+  pub(crate) controls: Vec<ControlMapping>,
+  #[serde(default)]
+  pub(crate) feedback: Option<String>,
+  pub(crate) name: Controller,
+  #[serde(default)]
+  pub(crate) port: Option<String>,
+}
+
+impl ControllerProfile {
+  fn contains(pattern: &str, port: &str) -> bool {
+    port.to_lowercase().contains(&pattern.to_lowercase())
+  }
+
+  pub(crate) fn control(&self, channel: u8, source: ControlSource) -> Option<u8> {
+    self
+      .controls
+      .iter()
+      .find(|mapping| mapping.channel == channel && mapping.source == source)
+      .map(|mapping| mapping.control)
+  }
+
+  pub(crate) fn defaults() -> Vec<Self> {
+    fn mapping(channel: u8, source: ControlSource, control: u8) -> ControlMapping {
+      ControlMapping {
+        channel,
+        control,
+        source,
+      }
+    }
+
+    let spectra = [
+      48, 49, 50, 51, 44, 45, 46, 47, 40, 41, 42, 43, 36, 37, 38, 39,
+    ]
+    .into_iter()
+    .map(|note| (2, note))
+    .chain([22, 21, 20, 25, 24, 23].into_iter().map(|note| (3, note)))
+    .zip(0..)
+    .map(|((channel, note), control)| mapping(channel, ControlSource::Note(note), control))
+    .collect();
+
+    let twister = (0..16)
+      .map(|control| mapping(0, ControlSource::Encoder(control), control))
+      .chain((0..16).map(|control| mapping(1, ControlSource::Note(control), control)))
+      .chain((8..14).map(|note| mapping(3, ControlSource::Note(note), note - 8 + 16)))
+      .collect();
+
+    vec![
+      Self {
+        controls: spectra,
+        feedback: Some("Midi Fighter Spectra".into()),
+        name: Controller::SPECTRA,
+        port: None,
+      },
+      Self {
+        controls: twister,
+        feedback: Some("Midi Fighter Twister".into()),
+        name: Controller::TWISTER,
+        port: None,
+      },
+    ]
+  }
+
+  pub(crate) fn feedback(
+    &self,
+    control: u8,
+    led: Led,
+    value: u7,
+  ) -> Option<midly::live::LiveEvent<'static>> {
+    self.controls.iter().find_map(|mapping| {
+      if mapping.control != control {
+        return None;
+      }
+
+      let message = match (led, mapping.source) {
+        (Led::Button, ControlSource::Note(key)) | (Led::Ring, ControlSource::Encoder(key)) => {
+          midly::MidiMessage::NoteOn {
+            key: u7::try_from(key)?,
+            vel: value,
+          }
+        }
+        (Led::Ring, ControlSource::Cc(controller)) => midly::MidiMessage::Controller {
+          controller: u7::try_from(controller)?,
+          value,
+        },
+        _ => return None,
+      };
+
+      Some(midly::live::LiveEvent::Midi {
+        channel: u4::try_from(mapping.channel)?,
+        message,
+      })
+    })
+  }
+
+  pub(crate) fn matches(&self, port: &str) -> bool {
+    self
+      .port
+      .as_ref()
+      .is_none_or(|pattern| Self::contains(pattern, port))
+  }
+
+  pub(crate) fn sends_feedback(&self, port: &str) -> bool {
+    self
+      .feedback
+      .as_ref()
+      .is_some_and(|pattern| Self::contains(pattern, port))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn defaults() {
+    let defaults = ControllerProfile::defaults();
+
+    assert!(
+      defaults
+        .iter()
+        .all(|profile| profile.matches("IAC Driver Bus 1"))
+    );
+    assert!(
+      !defaults
+        .iter()
+        .any(|profile| profile.sends_feedback("IAC Driver Bus 1"))
+    );
+
+    let spectra = &defaults[0];
+    assert!(spectra.sends_feedback("Midi Fighter Spectra:Midi Fighter Spectra MIDI 1 20:0"));
+    assert!(!spectra.sends_feedback("Midi Fighter Twister"));
+    assert_eq!(spectra.control(2, ControlSource::Note(48)), Some(0));
+    assert_eq!(spectra.control(2, ControlSource::Note(39)), Some(15));
+    assert_eq!(spectra.control(3, ControlSource::Note(22)), Some(16));
+    assert_eq!(spectra.control(3, ControlSource::Note(23)), Some(21));
+
+    let twister = &defaults[1];
+    assert!(twister.sends_feedback("Midi Fighter Twister"));
+    assert_eq!(twister.control(0, ControlSource::Encoder(7)), Some(7));
+    assert_eq!(twister.control(1, ControlSource::Note(7)), Some(7));
+    assert_eq!(twister.control(3, ControlSource::Note(8)), Some(16));
+    assert_eq!(twister.control(3, ControlSource::Note(13)), Some(21));
+  }
+
+  #[test]
+  fn deserialize() {
+    let profile = serde_yaml::from_str::<ControllerProfile>(
+      "
+name: launch-control
+port: Launch Control
+feedback: Launch Control XL
+controls:
+- {channel: 0, source: !note 41, control: 0}
+- {channel: 0, source: !cc 13, control: 1}
+- {channel: 1, source: pitch-bend, control: 2}
+- {channel: 1, source: channel-aftertouch, control: 3}
+",
+    )
+    .unwrap();
+
+    assert_eq!(profile.name.name(), "launch-control");
+    assert_eq!(profile.control(0, ControlSource::Note(41)), Some(0));
+    assert_eq!(profile.control(0, ControlSource::Cc(13)), Some(1));
+    assert_eq!(profile.control(1, ControlSource::PitchBend), Some(2));
+    assert_eq!(
+      profile.control(1, ControlSource::ChannelAftertouch),
+      Some(3)
+    );
+    assert_eq!(profile.control(1, ControlSource::Cc(13)), None);
+
+    assert!(profile.matches("Launch Control XL:Launch Control XL MIDI 1"));
+    assert!(profile.matches("launch control"));
+    assert!(!profile.matches("Midi Fighter Twister"));
+    assert!(profile.sends_feedback("Launch Control XL:Launch Control XL MIDI 1"));
+    assert!(!profile.sends_feedback("Launch Control"));
+  }
+}
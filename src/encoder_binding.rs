@@ -0,0 +1,9 @@
+use super::*;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct EncoderBinding {
+  pub(crate) control: u8,
+  pub(crate) controller: Controller,
+  pub(crate) parameter: Parameter,
+}
@@ -27,6 +27,12 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: cpal::BuildStreamError,
   },
+  #[snafu(display("failed to decode audio file `{path}`"))]
+  AudioDecode {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+    source: symphonia::core::errors::Error,
+  },
   #[snafu(display("failed to get default audio input device"))]
   AudioDefaultInputDevice { backtrace: Option<Backtrace> },
   #[snafu(display("failed to get default audio output device"))]
@@ -41,11 +47,26 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: cpal::DevicesError,
   },
+  #[snafu(display("unsupported audio file format: `{path}`"))]
+  AudioFormat {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+  },
+  #[snafu(display("failed to join audio thread"))]
+  AudioJoin {
+    backtrace: Option<Backtrace>,
+    panic_value: Box<dyn Any + Send + 'static>,
+  },
   #[snafu(display("failed to play audio input stream"))]
   AudioPlayStream {
     backtrace: Option<Backtrace>,
     source: cpal::PlayStreamError,
   },
+  #[snafu(display("audio format changed while decoding `{path}`"))]
+  AudioSpec {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+  },
   #[snafu(display("failed to get supported stream config"))]
   AudioSupportedStreamConfig { backtrace: Option<Backtrace> },
   #[snafu(display("failed to get supported stream configs"))]
@@ -53,16 +74,42 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: cpal::SupportedStreamConfigsError,
   },
-  #[snafu(display("app command {command} cannot be used in capture subcommand"))]
-  CaptureScriptAppCommand {
+  #[snafu(display("no decodable audio track in `{path}`"))]
+  AudioTrack {
     backtrace: Option<Backtrace>,
-    command: &'static str,
+    path: Utf8PathBuf,
   },
-  #[snafu(display("failed to launch command `{}`", program.display()))]
-  CommandRun {
+  #[snafu(display("command `{command}` in bindings takes arguments"))]
+  BindingArguments {
     backtrace: Option<Backtrace>,
-    program: OsString,
-    source: io::Error,
+    command: String,
+  },
+  #[snafu(display("unknown command `{command}` in bindings"))]
+  BindingCommand {
+    backtrace: Option<Backtrace>,
+    command: String,
+  },
+  #[snafu(display("conflicting bindings for {binding}"))]
+  BindingConflict {
+    backtrace: Option<Backtrace>,
+    binding: String,
+  },
+  #[snafu(display("cannot capture from position {position} without a tempo"))]
+  CapturePositionTempo {
+    backtrace: Option<Backtrace>,
+    position: Position,
+  },
+  #[snafu(display("capture end {end}s is not after start {start}s"))]
+  CaptureRange {
+    backtrace: Option<Backtrace>,
+    end: f64,
+    start: f64,
+  },
+  #[snafu(display("invalid arguments to command `{command}`"))]
+  CommandArguments {
+    backtrace: Option<Backtrace>,
+    command: &'static str,
+    source: ArgumentError,
   },
   #[snafu(display(
     "command `{}` failed with status {status}: {}",
@@ -75,14 +122,6 @@ pub(crate) enum Error {
     status: ExitStatus,
     stderr: Vec<u8>,
   },
-  #[snafu(display(
-    "command `{}` output not UTF-8", program.display(),
-  ))]
-  CommandUtf8 {
-    backtrace: Option<Backtrace>,
-    program: OsString,
-    source: FromUtf8Error,
-  },
   #[snafu(display("failed to deserialize config file at `{path}`"))]
   ConfigDeserialize {
     backtrace: Option<Backtrace>,
@@ -104,6 +143,11 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: winit::error::OsError,
   },
+  #[snafu(display("crossfade {crossfade}s is negative"))]
+  Crossfade {
+    backtrace: Option<Backtrace>,
+    crossfade: f64,
+  },
   #[snafu(display("failed to get current texture"))]
   CurrentTexture {
     backtrace: Option<Backtrace>,
@@ -142,11 +186,30 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: regex::Error,
   },
+  #[snafu(display(
+    "pattern `{pattern}` only matched unsupported files: {}",
+    matches.iter().map(|path| format!("`{path}`")).collect::<Vec<String>>().join(", "),
+  ))]
+  FindUnsupported {
+    backtrace: Option<Backtrace>,
+    matches: Vec<Utf8PathBuf>,
+    pattern: String,
+  },
   #[snafu(display("I/O error while searching"))]
   FindWalk {
     backtrace: Option<Backtrace>,
     source: walkdir::Error,
   },
+  #[snafu(display("app command {command} cannot be used in headless script"))]
+  HeadlessScriptAppCommand {
+    backtrace: Option<Backtrace>,
+    command: &'static str,
+  },
+  #[snafu(display("command {command} takes arguments and cannot be used in headless script"))]
+  HeadlessScriptArguments {
+    backtrace: Option<Backtrace>,
+    command: &'static str,
+  },
   #[snafu(display("could not get home directory"))]
   Home { backtrace: Option<Backtrace> },
   #[snafu(display("failed to decode image"))]
@@ -184,6 +247,12 @@ pub(crate) enum Error {
   },
   #[snafu(display("no music directory configured"))]
   Music { backtrace: Option<Backtrace> },
+  #[snafu(display("failed to bind OSC server to UDP port {port}"))]
+  OscBind {
+    backtrace: Option<Backtrace>,
+    port: u16,
+    source: io::Error,
+  },
   #[snafu(display("path not valid unicode: `{}`", path.display()))]
   PathUnicode {
     backtrace: Option<Backtrace>,
@@ -286,6 +355,52 @@ pub(crate) enum Error {
     fps: Fps,
     sample_rate: u32,
   },
+  #[snafu(display("failed to deserialize scene file at `{path}`"))]
+  SceneDeserialize {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+    source: serde_yaml::Error,
+  },
+  #[snafu(display("failed to serialize scene"))]
+  SceneSerialize {
+    backtrace: Option<Backtrace>,
+    source: serde_yaml::Error,
+  },
+  #[snafu(display("failed to parse script at `{path}`"))]
+  ScriptParse {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+    source: ScriptParseError,
+  },
+  #[snafu(display("cannot seek while synced to MIDI clock"))]
+  SeekClock { backtrace: Option<Backtrace> },
+  #[snafu(display("loop end {end} is not after start {start}"))]
+  SeekLoop {
+    backtrace: Option<Backtrace>,
+    end: Position,
+    start: Position,
+  },
+  #[snafu(display("position {position} is outside of the playing audio"))]
+  SeekRange {
+    backtrace: Option<Backtrace>,
+    position: Position,
+  },
+  #[snafu(display("no audio source is playing"))]
+  SeekSource { backtrace: Option<Backtrace> },
+  #[snafu(display("cannot seek without a tempo"))]
+  SeekTempo { backtrace: Option<Backtrace> },
+  #[snafu(display("failed to bind server to `{address}`"))]
+  ServerBind {
+    address: SocketAddr,
+    backtrace: Option<Backtrace>,
+    source: io::Error,
+  },
+  #[snafu(display("failed to parse session log at `{path}`"))]
+  SessionParse {
+    backtrace: Option<Backtrace>,
+    path: Utf8PathBuf,
+    source: SessionParseError,
+  },
   #[snafu(display("failed to reload shader template from `{path}`"))]
   ShaderReload {
     backtrace: Option<Backtrace>,
@@ -302,10 +417,10 @@ pub(crate) enum Error {
     backtrace: Option<Backtrace>,
     source: io::Error,
   },
-  #[snafu(display("failed to parse tempo from aubio stdout: {stdout}"))]
-  TempoParse {
+  #[snafu(display("failed to detect tempo of `{path}`"))]
+  TempoDetect {
     backtrace: Option<Backtrace>,
-    stdout: String,
+    path: Utf8PathBuf,
   },
   #[snafu(display("failed to spawn thread `{name}`"))]
   ThreadSpawn {
@@ -342,11 +457,6 @@ pub(crate) enum Error {
     path: Utf8PathBuf,
     source: hound::Error,
   },
-  #[snafu(display("failed to load audio track"))]
-  WaveLoad {
-    backtrace: Option<Backtrace>,
-    source: fundsp::read::WaveError,
-  },
   #[snafu(display("failed to resample audio"))]
   WaveResample {
     backtrace: Option<Backtrace>,
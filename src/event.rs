@@ -1,7 +1,10 @@
 use super::*;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum Event {
+  Aftertouch(u7),
   Button(Press),
+  Control(u7),
   Encoder(u7),
+  PitchBend(u14),
 }
@@ -0,0 +1,118 @@
+use super::*;
+
+#[derive(Default)]
+pub(crate) struct Feedback {
+  sent: BTreeMap<(Controller, u8, Led), u7>,
+}
+
+impl Feedback {
+  const ACTIVE: u8 = 127;
+  const BOUND: u8 = 32;
+
+  fn toggled(entry: CommandEntry, state: &State) -> bool {
+    match entry.name {
+      "spread" => state.spread,
+      "status" => state.status,
+      "toggle-fit" => matches!(state.viewport, Viewport::Fit),
+      "toggle-interpolate" => state.interpolate,
+      "toggle-repeat" => state.filter.repeat,
+      "toggle-tile" => state.tile,
+      "toggle-wrap" => state.wrap,
+      _ => false,
+    }
+  }
+
+  pub(crate) fn update(
+    &mut self,
+    bindings: &Bindings,
+    state: &State,
+  ) -> Vec<(Controller, u8, Led, u7)> {
+    let mut leds = BTreeMap::new();
+
+    for ((controller, control), entries) in bindings.buttons() {
+      let active = entries.into_iter().any(|entry| Self::toggled(entry, state));
+
+      leds.insert(
+        (controller.clone(), control, Led::Button),
+        u7::new(if active { Self::ACTIVE } else { Self::BOUND }),
+      );
+    }
+
+    for (controller, control, value) in bindings.rings(state) {
+      leds.insert((controller.clone(), control, Led::Ring), value);
+    }
+
+    let mut changed = Vec::new();
+
+    for ((controller, control, led), value) in leds {
+      if self.sent.get(&(controller.clone(), control, led)) != Some(&value) {
+        self.sent.insert((controller.clone(), control, led), value);
+        changed.push((controller, control, led, value));
+      }
+    }
+
+    changed
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn toggles() {
+    let bindings = Bindings::new(
+      &serde_yaml::from_str("buttons: [{controller: spectra, control: 11, command: toggle-wrap}]")
+        .unwrap(),
+    )
+    .unwrap();
+    let mut feedback = Feedback::default();
+    let mut state = State {
+      wrap: false,
+      ..default()
+    };
+
+    let changed = feedback.update(&bindings, &state);
+    assert!(changed.contains(&(Controller::SPECTRA, 11, Led::Button, u7::new(32))));
+
+    state.wrap = true;
+
+    assert_eq!(
+      feedback.update(&bindings, &state),
+      [(Controller::SPECTRA, 11, Led::Button, u7::new(127))],
+    );
+
+    state.wrap = false;
+
+    assert_eq!(
+      feedback.update(&bindings, &state),
+      [(Controller::SPECTRA, 11, Led::Button, u7::new(32))],
+    );
+  }
+
+  #[test]
+  fn update() {
+    let bindings = Bindings::new(&BindingConfig::default()).unwrap();
+    let mut feedback = Feedback::default();
+    let mut state = State::default();
+
+    let changed = feedback.update(&bindings, &state);
+    assert!(changed.contains(&(Controller::SPECTRA, 0, Led::Button, u7::new(32))));
+    assert!(changed.contains(&(Controller::TWISTER, 0, Led::Ring, u7::new(64))));
+    assert!(changed.contains(&(Controller::TWISTER, 1, Led::Ring, u7::new(64))));
+
+    assert!(feedback.update(&bindings, &state).is_empty());
+
+    state.alpha = 1.0;
+    state.db = -12.0;
+    state.push();
+
+    assert_eq!(
+      feedback.update(&bindings, &state),
+      [
+        (Controller::TWISTER, 0, Led::Ring, u7::new(127)),
+        (Controller::TWISTER, 1, Led::Ring, u7::new(52)),
+      ],
+    );
+  }
+}
@@ -1,7 +1,9 @@
 use super::*;
 
-#[derive(Clone, Copy, Debug, Default, EnumIter, IntoStaticStr)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, EnumIter, IntoStaticStr, Serialize)]
+#[allow(clippy::unsafe_derive_deserialize)]
 #[repr(u32)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum Field {
   #[default]
   All,
@@ -11,14 +13,18 @@ pub(crate) enum Field {
   },
   Cross,
   Frequencies,
+  Goniometer,
   Left,
   None,
   Right,
   Samples,
+  Spectrogram,
   Square,
+  Stereo,
   Texture,
   Top,
   Triangle,
+  Waveforms,
   X,
 }
 
@@ -38,14 +44,18 @@ impl Field {
       Self::Circle { .. } => '●',
       Self::Cross => '✚',
       Self::Frequencies => 'F',
+      Self::Goniometer => 'G',
       Self::Left => 'L',
       Self::None => '□',
       Self::Right => 'R',
       Self::Samples => 'S',
+      Self::Spectrogram => '▤',
       Self::Square => '■',
+      Self::Stereo => 'M',
       Self::Texture => '▧',
       Self::Top => 'T',
       Self::Triangle => '▲',
+      Self::Waveforms => '≋',
       Self::X => 'X',
     }
   }
@@ -1,6 +1,7 @@
 use super::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub(crate) struct Filter {
   pub(crate) alpha: f32,
   pub(crate) base: f32,
@@ -9,10 +10,12 @@ pub(crate) struct Filter {
   pub(crate) color_response: Transformation3,
   pub(crate) color_velocity: Transformation3,
   pub(crate) coordinates: bool,
+  #[serde(skip)]
   pub(crate) elapsed: Duration,
   pub(crate) field: Field,
   pub(crate) grid: f32,
   pub(crate) grid_transform: Mat3x2f,
+  #[serde(skip)]
   pub(crate) media: Option<MediaHandle>,
   pub(crate) mirror: Vector2<Mirror>,
   pub(crate) position: Mat3f,
@@ -21,6 +24,7 @@ pub(crate) struct Filter {
   pub(crate) preset: Option<Preset>,
   pub(crate) repeat: bool,
   pub(crate) rms: Mat1x2f,
+  pub(crate) source: Source,
   pub(crate) wrap: bool,
 }
 
@@ -46,6 +50,7 @@ impl Default for Filter {
       preset: None,
       repeat: true,
       rms: Mat1x2f::identity(),
+      source: Source::default(),
       wrap: false,
     }
   }
@@ -13,6 +13,7 @@ pub(crate) struct FilterUniforms {
   pub(crate) gain: f32,
   pub(crate) grid: f32,
   pub(crate) grid_transform: Mat3x2f,
+  pub(crate) history: f32,
   pub(crate) interpolate: bool,
   pub(crate) mirror: Vec4f,
   pub(crate) parameter: f32,
@@ -41,6 +42,7 @@ impl Uniforms for FilterUniforms {
     self.gain.write(dst, &mut i, &mut a);
     self.grid.write(dst, &mut i, &mut a);
     self.grid_transform.write(dst, &mut i, &mut a);
+    self.history.write(dst, &mut i, &mut a);
     self.interpolate.write(dst, &mut i, &mut a);
     self.mirror.write(dst, &mut i, &mut a);
     self.parameter.write(dst, &mut i, &mut a);
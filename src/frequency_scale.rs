@@ -0,0 +1,52 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum FrequencyScale {
+  Linear,
+  #[default]
+  Log,
+  Mel,
+}
+
+impl FrequencyScale {
+  pub(crate) fn frequency(self, value: f32) -> f32 {
+    match self {
+      Self::Linear => value,
+      Self::Log => value.exp(),
+      Self::Mel => 700.0 * (10f32.powf(value / 2595.0) - 1.0),
+    }
+  }
+
+  pub(crate) fn value(self, frequency: f32) -> f32 {
+    match self {
+      Self::Linear => frequency,
+      Self::Log => frequency.max(1.0).ln(),
+      Self::Mel => 2595.0 * (1.0 + frequency / 700.0).log10(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trip() {
+    for scale in [
+      FrequencyScale::Linear,
+      FrequencyScale::Log,
+      FrequencyScale::Mel,
+    ] {
+      for frequency in [20.0, 440.0, 1_000.0, 16_000.0] {
+        let actual = scale.frequency(scale.value(frequency));
+        assert!(
+          (actual - frequency).abs() < frequency * 1e-4,
+          "{scale:?}: {actual}"
+        );
+      }
+    }
+
+    assert!((FrequencyScale::Mel.value(1_000.0) - 1_000.0).abs() < 1.0);
+  }
+}
@@ -2,22 +2,275 @@ use super::*;
 
 #[derive(Default)]
 pub(crate) struct History {
-  pub(crate) commands: Vec<Command>,
-  pub(crate) states: Vec<State>,
+  pub(crate) commands: VecDeque<Invocation>,
+  pub(crate) redo: Vec<State>,
+  pub(crate) states: VecDeque<State>,
+  pub(crate) truncated: bool,
   pub(crate) unwind: bool,
 }
 
 impl History {
+  const LIMIT: usize = 1024;
+
+  pub(crate) fn dispatch(
+    &mut self,
+    rng: &mut SmallRng,
+    state: &mut State,
+    invocation: Invocation,
+  ) -> Result<(), ArgumentError> {
+    match invocation.entry.command {
+      Command::App(_)
+      | Command::AppEventLoop(_)
+      | Command::AppFallible(_)
+      | Command::Tap(_)
+      | Command::TapArguments(_) => {
+        unreachable!(
+          "app command {} dispatched to history",
+          invocation.entry.name
+        )
+      }
+      Command::History(command) => command(self),
+      Command::HistoryState(command) => command(self, state),
+      Command::RngState(command) => {
+        self.push(state.clone());
+        command(rng, state);
+      }
+      Command::RngStateArguments(command) => {
+        let previous = state.clone();
+        command(rng, state, &invocation.arguments)?;
+        self.push(previous);
+      }
+      Command::State(command) => {
+        self.push(state.clone());
+        command(state);
+      }
+      Command::StateArguments(command) => {
+        let previous = state.clone();
+        command(state, &invocation.arguments)?;
+        self.push(previous);
+      }
+    }
+
+    if self.commands.len() == Self::LIMIT {
+      self.commands.pop_front();
+      self.truncated = true;
+    }
+
+    self.commands.push_back(invocation);
+
+    Ok(())
+  }
+
+  fn push(&mut self, state: State) {
+    self.redo.clear();
+    self.save(state);
+  }
+
+  pub(crate) fn redo(&mut self, current: &mut State) {
+    if let Some(next) = self.redo.pop() {
+      self.save(mem::replace(current, next));
+    }
+  }
+
+  pub(crate) fn replay<'a>(
+    invocations: impl IntoIterator<Item = &'a Invocation>,
+    dt: Duration,
+    end: f64,
+    rng: &mut SmallRng,
+    state: &mut State,
+  ) -> Result<Self, ArgumentError> {
+    let mut history = Self::default();
+
+    let mut time = 0.0;
+
+    let mut advance = |history: &mut Self, state: &mut State, until: f64| {
+      while time + dt.as_secs_f64() <= until {
+        time += dt.as_secs_f64();
+        history.tick(state);
+        state.tick(Tick {
+          dt,
+          last: None,
+          position: None,
+          tempo: None,
+          time,
+        });
+      }
+    };
+
+    for invocation in invocations {
+      advance(&mut history, state, invocation.time);
+      history.dispatch(rng, state, invocation.clone())?;
+    }
+
+    advance(&mut history, state, end);
+
+    Ok(history)
+  }
+
+  fn save(&mut self, state: State) {
+    if self.states.len() == Self::LIMIT {
+      self.states.pop_front();
+    }
+
+    self.states.push_back(state);
+  }
+
   pub(crate) fn tick(&mut self, current: &mut State) {
     if !self.unwind {
       return;
     }
 
-    let Some(last) = self.states.pop() else {
+    if !self.undo(current) {
       self.unwind = false;
-      return;
+    }
+  }
+
+  pub(crate) fn undo(&mut self, current: &mut State) -> bool {
+    let Some(last) = self.states.pop_back() else {
+      return false;
     };
 
-    *current = last;
+    self.redo.push(mem::replace(current, last));
+
+    true
+  }
+
+  pub(crate) fn waffle(&mut self, current: &mut State) {
+    if let Some(last) = self.states.back_mut() {
+      mem::swap(last, current);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, generated::*};
+
+  fn dispatch(history: &mut History, rng: &mut SmallRng, state: &mut State, entry: CommandEntry) {
+    history
+      .dispatch(
+        rng,
+        state,
+        Invocation {
+          arguments: Vec::new(),
+          entry,
+          position: None,
+          time: 0.0,
+        },
+      )
+      .unwrap();
+  }
+
+  #[test]
+  fn limit() {
+    let mut history = History::default();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut state = State::new();
+
+    for _ in 0..=History::LIMIT {
+      dispatch(&mut history, &mut rng, &mut state, PUSH_TOP);
+    }
+
+    assert_eq!(history.states.len(), History::LIMIT);
+    assert_eq!(history.states.front().unwrap().filters.len(), 1);
+    assert_eq!(history.commands.len(), History::LIMIT);
+    assert!(history.truncated);
+  }
+
+  #[test]
+  fn redo() {
+    let mut history = History::default();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut state = State::new();
+
+    dispatch(&mut history, &mut rng, &mut state, PUSH_TOP);
+    dispatch(&mut history, &mut rng, &mut state, PUSH_TOP);
+    dispatch(&mut history, &mut rng, &mut state, UNDO);
+    dispatch(&mut history, &mut rng, &mut state, UNDO);
+    assert_eq!(state.filters.len(), 0);
+
+    dispatch(&mut history, &mut rng, &mut state, REDO);
+    assert_eq!(state.filters.len(), 1);
+
+    dispatch(&mut history, &mut rng, &mut state, PUSH_TOP);
+    assert!(history.redo.is_empty());
+
+    dispatch(&mut history, &mut rng, &mut state, REDO);
+    assert_eq!(state.filters.len(), 2);
+  }
+
+  #[test]
+  fn replay() {
+    let mut history = History::default();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut state = State::new();
+
+    for entry in [BLASTER, PUSH_TOP, UNDO, SHUFFLE, REDO, WAFFLE, PUSH_BOTTOM] {
+      dispatch(&mut history, &mut rng, &mut state, entry);
+    }
+
+    history
+      .dispatch(
+        &mut rng,
+        &mut state,
+        Invocation {
+          arguments: vec!["spin".into()],
+          entry: PRESET,
+          position: None,
+          time: 0.0,
+        },
+      )
+      .unwrap();
+
+    let mut replayed = State::new();
+
+    History::replay(
+      &history.commands,
+      Duration::from_millis(10),
+      0.0,
+      &mut SmallRng::seed_from_u64(0),
+      &mut replayed,
+    )
+    .unwrap();
+
+    assert_eq!(
+      serde_yaml::to_string(&replayed).unwrap(),
+      serde_yaml::to_string(&state).unwrap(),
+    );
+  }
+
+  #[test]
+  fn replay_unwind() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut state = State::new();
+
+    let invocations = [PUSH_TOP, PUSH_TOP, PUSH_TOP, UNWIND].map(|entry| Invocation {
+      arguments: Vec::new(),
+      entry,
+      position: None,
+      time: 0.0,
+    });
+
+    History::replay(
+      &invocations,
+      Duration::from_millis(10),
+      0.025,
+      &mut rng,
+      &mut state,
+    )
+    .unwrap();
+
+    assert_eq!(state.filters.len(), 1);
+
+    let history = History::replay(
+      &invocations,
+      Duration::from_millis(10),
+      0.05,
+      &mut SmallRng::seed_from_u64(0),
+      &mut State::new(),
+    )
+    .unwrap();
+
+    assert!(!history.unwind);
   }
 }
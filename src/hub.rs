@@ -1,17 +1,49 @@
 use super::*;
 
 pub(crate) struct Hub {
+  clock: Arc<Mutex<Option<Clock>>>,
   #[allow(unused)]
   connections: Vec<midir::MidiInputConnection<()>>,
+  feedback: Feedback,
   messages: Arc<Mutex<Vec<Message>>>,
+  outputs: Vec<(Vec<ControllerProfile>, midir::MidiOutputConnection)>,
 }
 
 impl Hub {
+  pub(crate) fn clock(&self) -> Option<Clock> {
+    self.clock.lock().unwrap().clone()
+  }
+
   pub(crate) fn drain(&self) -> Vec<Message> {
     self.messages.lock().unwrap().drain(..).collect()
   }
 
-  pub(crate) fn new() -> Result<Self> {
+  pub(crate) fn feedback(&mut self, bindings: &Bindings, state: &State) {
+    let changed = self.feedback.update(bindings, state);
+
+    for (profiles, connection) in &mut self.outputs {
+      for (controller, control, led, value) in &changed {
+        for profile in profiles
+          .iter()
+          .filter(|profile| profile.name == *controller)
+        {
+          let Some(event) = profile.feedback(*control, *led, *value) else {
+            continue;
+          };
+
+          let mut buffer = Vec::new();
+          event.write_std(&mut buffer).unwrap();
+
+          if let Err(err) = connection.send(&buffer) {
+            log::warn!("MIDI send error: {err}");
+          }
+        }
+      }
+    }
+  }
+
+  pub(crate) fn new(profiles: &[ControllerProfile]) -> Result<Self> {
+    let clock = Arc::new(Mutex::new(None::<Clock>));
     let messages = Arc::new(Mutex::new(Vec::new()));
 
     let mut connections = Vec::new();
@@ -19,6 +51,8 @@ impl Hub {
     let input = midir::MidiInput::new("MIDI Input").context(error::MidiInputInit)?;
     for port in input.ports() {
       let name = input.port_name(&port).context(error::MidiPortInfo)?;
+      let profiles = Self::profiles(profiles, &name);
+      let clock = clock.clone();
       let messages = messages.clone();
       connections.push(
         midir::MidiInput::new(&format!("MIDI Port Input: {name}"))
@@ -26,9 +60,20 @@ impl Hub {
           .connect(
             &port,
             &name,
-            move |_timestamp, event, ()| match Message::parse(event) {
-              Ok(message) => messages.lock().unwrap().push(message),
-              Err(err) => log::warn!("MIDI event parse error: {err}"),
+            move |timestamp, event, ()| {
+              if let Some(transport) = Transport::parse(event) {
+                clock
+                  .lock()
+                  .unwrap()
+                  .get_or_insert_default()
+                  .update(timestamp, transport);
+                return;
+              }
+
+              match Message::parse(&profiles, event) {
+                Ok(message) => messages.lock().unwrap().push(message),
+                Err(err) => log::warn!("MIDI event parse error: {err}"),
+              }
             },
             (),
           )
@@ -36,9 +81,102 @@ impl Hub {
       );
     }
 
+    let mut outputs = Vec::new();
+
+    let output = midir::MidiOutput::new("MIDI Output").context(error::MidiOutputInit)?;
+    for port in output.ports() {
+      let name = output.port_name(&port).context(error::MidiPortInfo)?;
+
+      let profiles = profiles
+        .iter()
+        .cloned()
+        .chain(ControllerProfile::defaults())
+        .filter(|profile| profile.sends_feedback(&name))
+        .collect::<Vec<ControllerProfile>>();
+
+      if profiles.is_empty() {
+        continue;
+      }
+
+      match midir::MidiOutput::new(&format!("MIDI Port Output: {name}"))
+        .context(error::MidiOutputInit)?
+        .connect(&port, &name)
+      {
+        Ok(connection) => outputs.push((profiles, connection)),
+        Err(err) => log::warn!("failed to connect to MIDI output {name}: {err}"),
+      }
+    }
+
     Ok(Self {
+      clock,
       connections,
+      feedback: Feedback::default(),
       messages,
+      outputs,
     })
   }
+
+  fn profiles(profiles: &[ControllerProfile], port: &str) -> Vec<ControllerProfile> {
+    profiles
+      .iter()
+      .cloned()
+      .chain(ControllerProfile::defaults())
+      .filter(|profile| profile.matches(port))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[ignore]
+  #[cfg(unix)]
+  fn feedback() {
+    use midir::os::unix::VirtualInput;
+
+    let (tx, rx) = mpsc::channel();
+
+    let _input = midir::MidiInput::new("device test")
+      .unwrap()
+      .create_virtual(
+        "device feedback test",
+        move |_timestamp, event, ()| tx.send(event.to_vec()).unwrap(),
+        (),
+      )
+      .unwrap();
+
+    let profiles = ControllerProfile::defaults()
+      .into_iter()
+      .map(|profile| ControllerProfile {
+        feedback: Some("device feedback test".into()),
+        ..profile
+      })
+      .collect::<Vec<ControllerProfile>>();
+
+    let mut hub = Hub::new(&profiles).unwrap();
+
+    let bindings = Bindings::new(&BindingConfig::default()).unwrap();
+
+    let mut state = State::default();
+
+    hub.feedback(&bindings, &state);
+
+    let mut expected = vec![vec![0x92, 48, 32], vec![0x90, 0, 64], vec![0x90, 1, 64]];
+
+    while !expected.is_empty() {
+      let event = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+      expected.retain(|expected| *expected != event);
+    }
+
+    state.db = -12.0;
+
+    hub.feedback(&bindings, &state);
+
+    assert_eq!(
+      rx.recv_timeout(Duration::from_secs(1)).unwrap(),
+      [0x90, 1, 52],
+    );
+  }
 }
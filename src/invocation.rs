@@ -0,0 +1,27 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub(crate) struct Invocation {
+  pub(crate) arguments: Vec<String>,
+  pub(crate) entry: CommandEntry,
+  pub(crate) position: Option<Position>,
+  pub(crate) time: f64,
+}
+
+impl Display for Invocation {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.time)?;
+
+    if let Some(position) = self.position {
+      write!(f, " {position}")?;
+    }
+
+    write!(f, " {}", self.entry.name)?;
+
+    for argument in &self.arguments {
+      write!(f, " {argument}")?;
+    }
+
+    Ok(())
+  }
+}
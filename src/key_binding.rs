@@ -0,0 +1,22 @@
+use super::*;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct KeyBinding {
+  pub(crate) command: String,
+  pub(crate) key: BindingKey,
+  pub(crate) mode: ModeKind,
+  #[serde(default)]
+  pub(crate) modifiers: Vec<Modifier>,
+}
+
+impl KeyBinding {
+  pub(crate) fn modifiers(&self) -> ModifiersState {
+    self
+      .modifiers
+      .iter()
+      .fold(ModifiersState::empty(), |state, modifier| {
+        state | modifier.state()
+      })
+  }
+}
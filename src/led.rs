@@ -0,0 +1,7 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Led {
+  Button,
+  Ring,
+}
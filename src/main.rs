@@ -4,14 +4,24 @@ use {
     allocator::Allocator,
     analyzer::Analyzer,
     app::App,
+    argument::Argument,
+    argument_error::ArgumentError,
     arguments::Arguments,
+    audio_format::AudioFormat,
+    audio_thread::AudioThread,
     axis::Axis,
+    band::Band,
+    band_config::BandConfig,
+    binding_config::BindingConfig,
+    binding_key::BindingKey,
     bindings::Bindings,
     blend_mode::BlendMode,
     bool_ext::BoolExt,
+    button_binding::ButtonBinding,
     callback::Callback,
     capture::Capture,
     capture_thread::CaptureThread,
+    clock::Clock,
     codepoint::Codepoint,
     command::Command,
     command_entry::CommandEntry,
@@ -19,15 +29,21 @@ use {
     commands::Commands,
     composite_uniforms::CompositeUniforms,
     config::Config,
+    control_mapping::ControlMapping,
+    control_source::ControlSource,
     controller::Controller,
+    controller_profile::ControllerProfile,
     counter::Counter,
+    encoder_binding::EncoderBinding,
     error::Error,
     event::Event,
+    feedback::Feedback,
     field::Field,
     filter::Filter,
     filter_uniforms::FilterUniforms,
     fps::Fps,
     frame::Frame,
+    frequency_scale::FrequencyScale,
     functions::{default, display, tempdir, thread_spawn},
     history::History,
     hub::Hub,
@@ -37,31 +53,50 @@ use {
     interrupt::Interrupt,
     into_stereo::IntoStereo,
     into_utf8_path::IntoUtf8Path,
+    invocation::Invocation,
+    key_binding::KeyBinding,
+    led::Led,
     media::Media,
     media_handle::MediaHandle,
     message::Message,
     mirror::Mirror,
     mode::{Mode, ModeKind},
+    modifier::Modifier,
+    onset_binding::OnsetBinding,
     options::Options,
+    osc_message::OscMessage,
+    osc_server::OscServer,
+    parameter::Parameter,
     patch::Patch,
     pipeline::Pipeline,
+    playback::Playback,
+    playlist::Playlist,
     position::Position,
+    preload::Preload,
     present_mode::PresentMode,
     preset::Preset,
     press::Press,
     program::Program,
+    readback::Readback,
     recorder::Recorder,
     recorder_thread::RecorderThread,
     renderer::Renderer,
     resources::Resources,
     scene::Scene,
     score::Score,
-    script::Script,
+    script::{Script, ScriptParseError},
+    server::Server,
+    session::{Session, SessionParseError},
+    session_log::SessionLog,
     shared::Shared,
+    simulation::Simulation,
     sound::Sound,
     sound_format::SoundFormat,
+    source::Source,
     space::Space,
+    spectrum_config::SpectrumConfig,
     state::State,
+    status::Status,
     stream_config_display::StreamConfigDisplay,
     subcommand::Subcommand,
     tally::Tally,
@@ -71,14 +106,18 @@ use {
     tempo::Tempo,
     tick::Tick,
     tiling::Tiling,
+    timestamp::Timestamp,
     to_affine::ToAffine,
     track::Track,
     transformation2::Transformation2,
     transformation3::Transformation3,
+    transport::Transport,
     uniforms::Uniforms,
+    video_format::VideoFormat,
     viewport::Viewport,
     wave_adapter::WaveAdapter,
     window_attributes_ext::WindowAttributesExt,
+    window_function::WindowFunction,
   },
   ::image::{AnimationDecoder, codecs::gif::GifDecoder},
   audioadapter::{Adapter, AdapterMut},
@@ -93,16 +132,16 @@ use {
   fundsp::wave::Wave,
   hound::{WavSpec, WavWriter},
   indicatif::{ProgressBar, ProgressStyle},
-  midly::num::u7,
+  midly::num::{u4, u7, u14},
   nalgebra::{
     Rotation2, Translation2, Translation3, Unit, UnitQuaternion, Vector2, matrix, vector,
   },
   parley::{FontContext, FontFamily, FontStack, FontWeight, GenericFamily, LayoutContext},
   rand::{Rng, SeedableRng, prelude::SliceRandom, rngs::SmallRng, seq::IndexedRandom},
-  regex::{Regex, RegexBuilder},
+  regex::RegexBuilder,
   rubato::Resampler,
   rustfft::{FftPlanner, num_complex::Complex},
-  serde::Deserialize,
+  serde::{Deserialize, Serialize},
   snafu::{ErrorCompat, IntoError, OptionExt, ResultExt, Snafu, ensure},
   std::{
     any::Any,
@@ -116,12 +155,12 @@ use {
     fs::{self, File},
     io::{self, BufReader, BufWriter, Write},
     mem,
+    net::SocketAddr,
     num::NonZeroU32,
     ops::{Add, Bound, Range, Sub},
     process::{self, ExitStatus, Stdio},
     rc::Rc,
     str::FromStr,
-    string::FromUtf8Error,
     sync::{
       Arc, LazyLock, Mutex,
       atomic::{self, AtomicBool, AtomicU64, AtomicUsize},
@@ -148,10 +187,10 @@ use {
     PipelineLayoutDescriptor, PowerPreference, PrimitiveState, Queue, RenderPass,
     RenderPassColorAttachment, RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor,
     RequestAdapterOptions, Sampler, SamplerBindingType, SamplerDescriptor, ShaderModuleDescriptor,
-    ShaderSource, ShaderStages, StoreOp, Surface, SurfaceConfiguration, TexelCopyBufferInfo,
-    TexelCopyBufferLayout, TexelCopyTextureInfo, Texture, TextureAspect, TextureDescriptor,
-    TextureDimension, TextureFormat, TextureSampleType, TextureUsages, TextureView,
-    TextureViewDescriptor, TextureViewDimension, Trace, VertexState,
+    ShaderSource, ShaderStages, StoreOp, SubmissionIndex, Surface, SurfaceConfiguration,
+    TexelCopyBufferInfo, TexelCopyBufferLayout, TexelCopyTextureInfo, Texture, TextureAspect,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureSampleType, TextureUsages,
+    TextureView, TextureViewDescriptor, TextureViewDimension, Trace, VertexState,
   },
   winit::{
     application::ApplicationHandler,
@@ -167,16 +206,26 @@ mod alignment;
 mod allocator;
 mod analyzer;
 mod app;
+mod argument;
+mod argument_error;
 mod arguments;
 #[cfg(test)]
 mod array_string;
+mod audio_format;
+mod audio_thread;
 mod axis;
+mod band;
+mod band_config;
+mod binding_config;
+mod binding_key;
 mod bindings;
 mod blend_mode;
 mod bool_ext;
+mod button_binding;
 mod callback;
 mod capture;
 mod capture_thread;
+mod clock;
 mod codepoint;
 mod color;
 mod command;
@@ -185,15 +234,21 @@ mod command_ext;
 mod commands;
 mod composite_uniforms;
 mod config;
+mod control_mapping;
+mod control_source;
 mod controller;
+mod controller_profile;
 mod counter;
+mod encoder_binding;
 mod error;
 mod event;
+mod feedback;
 mod field;
 mod filter;
 mod filter_uniforms;
 mod fps;
 mod frame;
+mod frequency_scale;
 mod functions;
 mod generated;
 mod history;
@@ -204,19 +259,31 @@ mod input;
 mod interrupt;
 mod into_stereo;
 mod into_utf8_path;
+mod invocation;
+mod key_binding;
+mod led;
 mod media;
 mod media_handle;
 mod message;
 mod mirror;
 mod mode;
+mod modifier;
+mod onset_binding;
 mod options;
+mod osc_message;
+mod osc_server;
+mod parameter;
 mod patch;
 mod pipeline;
+mod playback;
+mod playlist;
 mod position;
+mod preload;
 mod present_mode;
 mod preset;
 mod press;
 mod program;
+mod readback;
 mod recorder;
 mod recorder_thread;
 #[cfg(test)]
@@ -226,11 +293,18 @@ mod resources;
 mod scene;
 mod score;
 mod script;
+mod server;
+mod session;
+mod session_log;
 mod shared;
+mod simulation;
 mod sound;
 mod sound_format;
+mod source;
 mod space;
+mod spectrum_config;
 mod state;
+mod status;
 mod stream_config_display;
 mod subcommand;
 mod tally;
@@ -240,14 +314,18 @@ mod templates;
 mod tempo;
 mod tick;
 mod tiling;
+mod timestamp;
 mod to_affine;
 mod track;
 mod transformation2;
 mod transformation3;
+mod transport;
 mod uniforms;
+mod video_format;
 mod viewport;
 mod wave_adapter;
 mod window_attributes_ext;
+mod window_function;
 
 const KIB: usize = 1 << 10;
 const MIB: usize = KIB << 10;
@@ -265,7 +343,8 @@ const DEFAULT_FONT_STACK: FontStack<'static> = FontStack::List(Cow::Borrowed(&[
 ]));
 const DEFAULT_FPS: NonZeroU32 = NonZeroU32::new(60).unwrap();
 const DEFAULT_RESOLUTION: NonZeroU32 = NonZeroU32::new(1024).unwrap();
-const RECORDING: &str = "recording.mp4";
+const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+const NUDGE: f64 = 0.01;
 const TAU: f32 = f32::consts::TAU;
 const TIME: u64 = 4;
 
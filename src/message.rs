@@ -11,7 +11,7 @@ pub(crate) enum MessageParseError {
   },
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub(crate) struct Message {
   pub(crate) control: u8,
   pub(crate) controller: Controller,
@@ -19,78 +19,142 @@ pub(crate) struct Message {
 }
 
 impl Message {
-  pub(crate) fn parse(event: &[u8]) -> Result<Self, MessageParseError> {
+  pub(crate) fn parse(
+    profiles: &[ControllerProfile],
+    event: &[u8],
+  ) -> Result<Self, MessageParseError> {
     let event = midly::live::LiveEvent::parse(event).context(ParseError)?;
-    let (channel, key, value, press): (u8, u8, u7, Press) = match event {
-      midly::live::LiveEvent::Midi { channel, message } => match message {
-        midly::MidiMessage::NoteOn { key, vel } => (channel.into(), key.into(), vel, Press::Press),
-        midly::MidiMessage::NoteOff { key, vel } => {
-          (channel.into(), key.into(), vel, Press::Release)
-        }
-        _ => {
-          return Err(MessageParseError::Unrecognized {
-            event: event.to_static(),
+
+    let unrecognized = || MessageParseError::Unrecognized {
+      event: event.to_static(),
+    };
+
+    let midly::live::LiveEvent::Midi { channel, message } = event else {
+      return Err(unrecognized());
+    };
+
+    let channel = channel.into();
+
+    let candidates = match message {
+      midly::MidiMessage::Aftertouch { key, vel } => vec![(
+        ControlSource::Aftertouch(key.into()),
+        Event::Aftertouch(vel),
+      )],
+      midly::MidiMessage::ChannelAftertouch { vel } => {
+        vec![(ControlSource::ChannelAftertouch, Event::Aftertouch(vel))]
+      }
+      midly::MidiMessage::Controller { controller, value } => {
+        vec![(ControlSource::Cc(controller.into()), Event::Control(value))]
+      }
+      midly::MidiMessage::NoteOff { key, vel } => vec![
+        (
+          ControlSource::Note(key.into()),
+          Event::Button(Press::Release),
+        ),
+        (ControlSource::Encoder(key.into()), Event::Encoder(vel)),
+      ],
+      midly::MidiMessage::NoteOn { key, vel } => vec![
+        (ControlSource::Note(key.into()), Event::Button(Press::Press)),
+        (ControlSource::Encoder(key.into()), Event::Encoder(vel)),
+      ],
+      midly::MidiMessage::PitchBend { bend } => {
+        vec![(ControlSource::PitchBend, Event::PitchBend(bend.0))]
+      }
+      midly::MidiMessage::ProgramChange { .. } => return Err(unrecognized()),
+    };
+
+    for profile in profiles {
+      for &(source, event) in &candidates {
+        if let Some(control) = profile.control(channel, source) {
+          return Ok(Self {
+            control,
+            controller: profile.name.clone(),
+            event,
           });
         }
-      },
-      _ => {
-        return Err(MessageParseError::Unrecognized {
-          event: event.to_static(),
-        });
       }
-    };
+    }
 
-    let (controller, control, event) = match (channel, key) {
-      (0, 0..=15) => (Controller::Twister, key, Event::Encoder(value)),
-      (1, 0..=15) => (Controller::Twister, key, Event::Button(press)),
-      (2, 36..=51) => (
-        Controller::Spectra,
-        match key {
-          48 => 0,
-          49 => 1,
-          50 => 2,
-          51 => 3,
-          44 => 4,
-          45 => 5,
-          46 => 6,
-          47 => 7,
-          40 => 8,
-          41 => 9,
-          42 => 10,
-          43 => 11,
-          36 => 12,
-          37 => 13,
-          38 => 14,
-          39 => 15,
-          _ => unreachable!(),
-        },
-        Event::Button(press),
-      ),
-      (3, 20..=25) => (
-        Controller::Spectra,
-        match key {
-          22 => 16,
-          21 => 17,
-          20 => 18,
-          25 => 19,
-          24 => 20,
-          23 => 21,
-          _ => unreachable!(),
+    Err(unrecognized())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    #[track_caller]
+    fn case(event: &[u8], controller: &Controller, control: u8, expected: Event) {
+      assert_eq!(
+        Message::parse(&profiles(), event).unwrap(),
+        Message {
+          control,
+          controller: controller.clone(),
+          event: expected,
         },
-        Event::Button(press),
-      ),
-      (3, 8..=13) => (Controller::Twister, key - 8 + 16, Event::Button(press)),
-      _ => {
-        return Err(MessageParseError::Unrecognized {
-          event: event.to_static(),
-        });
-      }
-    };
+      );
+    }
+
+    fn profiles() -> Vec<ControllerProfile> {
+      let mut profiles = vec![
+        serde_yaml::from_str(
+          "
+name: keys
+controls:
+- {channel: 4, source: !cc 1, control: 0}
+- {channel: 4, source: pitch-bend, control: 1}
+- {channel: 4, source: channel-aftertouch, control: 2}
+- {channel: 4, source: !aftertouch 60, control: 3}
+- {channel: 4, source: !note 60, control: 4}
+",
+        )
+        .unwrap(),
+      ];
+      profiles.extend(ControllerProfile::defaults());
+      profiles
+    }
+
+    let keys = serde_yaml::from_str::<Controller>("keys").unwrap();
+
+    case(
+      &[0x90, 0x05, 0x40],
+      &Controller::TWISTER,
+      5,
+      Event::Encoder(u7::new(0x40)),
+    );
+    case(
+      &[0x91, 0x05, 0x7F],
+      &Controller::TWISTER,
+      5,
+      Event::Button(Press::Press),
+    );
+    case(
+      &[0x82, 0x30, 0x00],
+      &Controller::SPECTRA,
+      0,
+      Event::Button(Press::Release),
+    );
+    case(&[0xB4, 0x01, 0x20], &keys, 0, Event::Control(u7::new(0x20)));
+    case(
+      &[0xE4, 0x00, 0x40],
+      &keys,
+      1,
+      Event::PitchBend(u14::new(0x2000)),
+    );
+    case(&[0xD4, 0x10], &keys, 2, Event::Aftertouch(u7::new(0x10)));
+    case(
+      &[0xA4, 0x3C, 0x10],
+      &keys,
+      3,
+      Event::Aftertouch(u7::new(0x10)),
+    );
+    case(&[0x84, 0x3C, 0x00], &keys, 4, Event::Button(Press::Release));
 
-    Ok(Self {
-      control,
-      controller,
-      event,
-    })
+    assert!(matches!(
+      Message::parse(&profiles(), &[0xB5, 0x01, 0x20]),
+      Err(MessageParseError::Unrecognized { .. }),
+    ));
   }
 }
@@ -1,5 +1,8 @@
+use super::*;
+
 #[allow(clippy::arbitrary_source_item_ordering)]
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum Mirror {
   #[default]
   Off,
@@ -2,7 +2,8 @@ use super::*;
 
 #[derive(Debug, EnumDiscriminants)]
 #[strum_discriminants(name(ModeKind))]
-#[strum_discriminants(derive(Hash, Ord, PartialOrd, IntoStaticStr))]
+#[strum_discriminants(derive(Deserialize, Hash, Ord, PartialOrd, IntoStaticStr))]
+#[strum_discriminants(serde(rename_all = "kebab-case"))]
 #[strum_discriminants(allow(clippy::arbitrary_source_item_ordering))]
 #[allow(clippy::arbitrary_source_item_ordering)]
 pub(crate) enum Mode {
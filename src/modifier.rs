@@ -0,0 +1,21 @@
+use super::*;
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Modifier {
+  Alt,
+  Control,
+  Shift,
+  Super,
+}
+
+impl Modifier {
+  pub(crate) fn state(self) -> ModifiersState {
+    match self {
+      Self::Alt => ModifiersState::ALT,
+      Self::Control => ModifiersState::CONTROL,
+      Self::Shift => ModifiersState::SHIFT,
+      Self::Super => ModifiersState::SUPER,
+    }
+  }
+}
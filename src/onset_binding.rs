@@ -0,0 +1,8 @@
+use super::*;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct OnsetBinding {
+  pub(crate) band: Band,
+  pub(crate) command: String,
+}
@@ -18,6 +18,8 @@ const AUDIO: &str = "audio";
     .valid(AnsiColor::Green.on_default())
 )]
 pub(crate) struct Options {
+  #[arg(long)]
+  pub(crate) crossfade: Option<f64>,
   #[arg(allow_hyphen_values = true, long)]
   pub(crate) db: Option<f32>,
   #[arg(long)]
@@ -34,6 +36,8 @@ pub(crate) struct Options {
   pub(crate) interpolate: bool,
   #[arg(long)]
   pub(crate) mute: bool,
+  #[arg(group = AUDIO, long)]
+  pub(crate) playlist: Option<Utf8PathBuf>,
   #[arg(long)]
   pub(crate) preset: Option<Vec<Preset>>,
   #[arg(long)]
@@ -42,12 +46,24 @@ pub(crate) struct Options {
   pub(crate) resolution: Option<NonZeroU32>,
   #[arg(long)]
   pub(crate) scene: Option<Scene>,
+  #[arg(conflicts_with = "scene", long)]
+  pub(crate) scene_file: Option<Utf8PathBuf>,
   #[arg(group = AUDIO, long)]
   pub(crate) score: Option<Score>,
   #[arg(long)]
+  pub(crate) script: Option<Utf8PathBuf>,
+  #[arg(long)]
   pub(crate) seed: Option<u64>,
   #[arg(group = AUDIO, long)]
-  pub(crate) song: Option<String>,
+  pub(crate) song: Option<Vec<String>>,
+  #[arg(long)]
+  pub(crate) spectrum_db: bool,
+  #[arg(long)]
+  pub(crate) spectrum_scale: Option<FrequencyScale>,
+  #[arg(long)]
+  pub(crate) spectrum_size: Option<usize>,
+  #[arg(long)]
+  pub(crate) spectrum_window: Option<WindowFunction>,
   #[arg(long)]
   pub(crate) status: bool,
   #[arg(group = AUDIO, long)]
@@ -68,9 +84,23 @@ pub(crate) struct Options {
 
 impl Options {
   pub(crate) fn add_source(&self, config: &Config, tap: &mut Tap) -> Result {
-    if let Some(song) = &self.song {
+    let crossfade = self.crossfade.unwrap_or_default();
+
+    ensure!(crossfade >= 0.0, error::Crossfade { crossfade });
+
+    if let Some(path) = &self.playlist {
+      tap.queue(Playlist::load(path)?, crossfade);
+    } else if let Some([song]) = self.song.as_deref() {
       let wave = tap.load_wave(&config.find_song(song)?)?;
       tap.sequence_wave(&wave, 0.0, 0.0);
+    } else if let Some(songs) = &self.song {
+      let playlist = Playlist {
+        tracks: songs
+          .iter()
+          .map(|song| config.find_song(song))
+          .collect::<Result<Vec<Utf8PathBuf>>>()?,
+      };
+      tap.queue(playlist, crossfade);
     } else if let Some(score) = self.score {
       score.sequence(tap);
     } else if let Some(track) = &self.track {
@@ -83,16 +113,39 @@ impl Options {
     Ok(())
   }
 
+  pub(crate) fn renderer(&self) -> Result<Renderer> {
+    let resolution = self.resolution.unwrap_or(DEFAULT_RESOLUTION);
+
+    let size = Size::new(
+      self.width.unwrap_or(resolution),
+      self.height.unwrap_or(resolution),
+    );
+
+    let (size, resolution) = self.size(size);
+
+    pollster::block_on(Renderer::new(
+      self.image_format,
+      None,
+      resolution,
+      size,
+      None,
+    ))
+  }
+
   pub(crate) fn rng(&self) -> SmallRng {
-    if let Some(seed) = self.seed {
-      SmallRng::seed_from_u64(seed)
+    SmallRng::seed_from_u64(self.seed())
+  }
+
+  pub(crate) fn script(&self) -> Result<Option<Script>> {
+    if let Some(path) = &self.script {
+      Ok(Some(Script::load(path)?))
     } else {
-      SmallRng::from_rng(&mut rand::rng())
+      Ok(self.program.and_then(Program::script))
     }
   }
 
-  pub(crate) fn script(&self) -> Option<Script> {
-    self.program.and_then(Program::script)
+  pub(crate) fn seed(&self) -> u64 {
+    self.seed.unwrap_or_else(rand::random)
   }
 
   pub(crate) fn size(&self, size: Size) -> (Size, NonZeroU32) {
@@ -102,7 +155,9 @@ impl Options {
   }
 
   pub(crate) fn state(&self, config: &Config, rng: &mut SmallRng) -> Result<State> {
-    let mut state = if let Some(scene) = self.scene {
+    let mut state = if let Some(path) = &self.scene_file {
+      State::load(path)?
+    } else if let Some(scene) = self.scene {
       scene.state(rng)
     } else if let Some(program) = self.program {
       program.state(config, rng)?
@@ -133,6 +188,14 @@ impl Options {
       state.interpolate = true;
     }
 
+    if self.spectrum_db {
+      state.spectrum.db = true;
+    }
+
+    state.spectrum.scale = self.spectrum_scale.unwrap_or(state.spectrum.scale);
+    state.spectrum.size = self.spectrum_size.unwrap_or(state.spectrum.size);
+    state.spectrum.window = self.spectrum_window.unwrap_or(state.spectrum.window);
+
     if self.status {
       state.status = true;
     }
@@ -0,0 +1,193 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(context(suffix(Error)), visibility(pub(crate)))]
+pub(crate) enum OscParseError {
+  #[snafu(display("invalid OSC address `{address}`"))]
+  Address { address: String },
+  #[snafu(display("unsupported OSC argument type `{tag}`"))]
+  Argument { tag: char },
+  #[snafu(display("invalid OSC string"))]
+  String,
+  #[snafu(display("truncated OSC packet"))]
+  Truncated,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct OscMessage {
+  pub(crate) address: String,
+  pub(crate) arguments: Vec<String>,
+}
+
+impl OscMessage {
+  const BUNDLE: &[u8] = b"#bundle\0";
+
+  pub(crate) fn parse(packet: &[u8]) -> Result<Vec<Self>, OscParseError> {
+    fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8], OscParseError> {
+      ensure!(bytes.len() >= n, TruncatedError);
+      let (head, tail) = bytes.split_at(n);
+      *bytes = tail;
+      Ok(head)
+    }
+
+    fn word(bytes: &mut &[u8]) -> Result<[u8; 4], OscParseError> {
+      Ok(take(bytes, 4)?.try_into().unwrap())
+    }
+
+    fn string(bytes: &mut &[u8]) -> Result<String, OscParseError> {
+      let end = bytes
+        .iter()
+        .position(|&byte| byte == 0)
+        .context(TruncatedError)?;
+      let string = str::from_utf8(&bytes[..end])
+        .ok()
+        .context(StringError)?
+        .to_owned();
+      take(bytes, (end + 4) & !3)?;
+      Ok(string)
+    }
+
+    fn message(mut bytes: &[u8]) -> Result<OscMessage, OscParseError> {
+      let address = string(&mut bytes)?;
+
+      ensure!(address.starts_with('/'), AddressError { address });
+
+      let mut arguments = Vec::new();
+
+      if bytes.is_empty() {
+        return Ok(OscMessage { address, arguments });
+      }
+
+      let tags = string(&mut bytes)?;
+
+      for tag in tags.strip_prefix(',').context(StringError)?.chars() {
+        arguments.push(match tag {
+          'd' => f64::from_be_bytes(take(&mut bytes, 8)?.try_into().unwrap()).to_string(),
+          'f' => f32::from_be_bytes(word(&mut bytes)?).to_string(),
+          'h' => i64::from_be_bytes(take(&mut bytes, 8)?.try_into().unwrap()).to_string(),
+          'i' => i32::from_be_bytes(word(&mut bytes)?).to_string(),
+          's' => string(&mut bytes)?,
+          'F' => "false".into(),
+          'T' => "true".into(),
+          _ => return Err(OscParseError::Argument { tag }),
+        });
+      }
+
+      Ok(OscMessage { address, arguments })
+    }
+
+    fn element(mut bytes: &[u8], messages: &mut Vec<OscMessage>) -> Result<(), OscParseError> {
+      if !bytes.starts_with(OscMessage::BUNDLE) {
+        messages.push(message(bytes)?);
+        return Ok(());
+      }
+
+      take(&mut bytes, OscMessage::BUNDLE.len() + 8)?;
+
+      while !bytes.is_empty() {
+        let size = u32::from_be_bytes(word(&mut bytes)?);
+        element(take(&mut bytes, size.into_usize())?, messages)?;
+      }
+
+      Ok(())
+    }
+
+    let mut messages = Vec::new();
+    element(packet, &mut messages)?;
+    Ok(messages)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bundle() {
+    let blaster = encode("/command/blaster", &[], "");
+    let db = encode("/state/db", &[&(-12.0f32).to_be_bytes()], "f");
+
+    let mut bytes = b"#bundle\0".to_vec();
+    bytes.extend([0, 0, 0, 0, 0, 0, 0, 1]);
+    for message in [&blaster, &db] {
+      bytes.extend(u32::try_from(message.len()).unwrap().to_be_bytes());
+      bytes.extend(message);
+    }
+
+    assert_eq!(
+      OscMessage::parse(&bytes).unwrap(),
+      [
+        OscMessage {
+          address: "/command/blaster".into(),
+          arguments: Vec::new(),
+        },
+        OscMessage {
+          address: "/state/db".into(),
+          arguments: vec!["-12".into()],
+        },
+      ],
+    );
+  }
+
+  fn encode(address: &str, arguments: &[&[u8]], tags: &str) -> Vec<u8> {
+    fn string(bytes: &mut Vec<u8>, s: &str) {
+      bytes.extend(s.as_bytes());
+      bytes.push(0);
+      while !bytes.len().is_multiple_of(4) {
+        bytes.push(0);
+      }
+    }
+
+    let mut bytes = Vec::new();
+    string(&mut bytes, address);
+    string(&mut bytes, &format!(",{tags}"));
+    for argument in arguments {
+      bytes.extend(*argument);
+    }
+    bytes
+  }
+
+  #[test]
+  fn errors() {
+    assert_eq!(
+      OscMessage::parse(&encode("command", &[], "")),
+      Err(OscParseError::Address {
+        address: "command".into()
+      }),
+    );
+
+    assert_eq!(
+      OscMessage::parse(&encode("/state/db", &[], "b")),
+      Err(OscParseError::Argument { tag: 'b' }),
+    );
+
+    assert_eq!(
+      OscMessage::parse(&encode("/state/db", &[&[0, 0]], "f")),
+      Err(OscParseError::Truncated),
+    );
+  }
+
+  #[test]
+  fn message() {
+    assert_eq!(
+      OscMessage::parse(&encode(
+        "/state/velocity/x",
+        &[&0.2f32.to_be_bytes(), &7i32.to_be_bytes(), b"foo\0"],
+        "fis",
+      ))
+      .unwrap(),
+      [OscMessage {
+        address: "/state/velocity/x".into(),
+        arguments: vec!["0.2".into(), "7".into(), "foo".into()],
+      }],
+    );
+
+    assert_eq!(
+      OscMessage::parse(b"/command/blaster\0\0\0\0").unwrap(),
+      [OscMessage {
+        address: "/command/blaster".into(),
+        arguments: Vec::new(),
+      }],
+    );
+  }
+}
@@ -0,0 +1,63 @@
+use {super::*, std::net::UdpSocket};
+
+pub(crate) struct OscServer {
+  messages: Arc<Mutex<Vec<OscMessage>>>,
+}
+
+impl OscServer {
+  const BACKOFF_MAX: Duration = Duration::from_secs(1);
+  const BACKOFF_MIN: Duration = Duration::from_millis(10);
+
+  pub(crate) fn drain(&self) -> Vec<OscMessage> {
+    self.messages.lock().unwrap().drain(..).collect()
+  }
+
+  pub(crate) fn new(port: u16) -> Result<Self> {
+    let socket = UdpSocket::bind(("0.0.0.0", port)).context(error::OscBind { port })?;
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+
+    thread_spawn("osc", {
+      let messages = messages.clone();
+      move || {
+        let mut backoff = Self::BACKOFF_MIN;
+        let mut buffer = vec![0; 65536];
+        loop {
+          let size = match socket.recv(&mut buffer) {
+            Ok(size) => {
+              backoff = Self::BACKOFF_MIN;
+              size
+            }
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err)
+              if matches!(
+                err.kind(),
+                io::ErrorKind::ConnectionReset
+                  | io::ErrorKind::TimedOut
+                  | io::ErrorKind::WouldBlock
+              ) =>
+            {
+              log::warn!("OSC receive error: {err}");
+              std::thread::sleep(backoff);
+              backoff = (backoff * 2).min(Self::BACKOFF_MAX);
+              continue;
+            }
+            Err(err) => {
+              log::error!("OSC receive error, stopping OSC server: {err}");
+              return;
+            }
+          };
+
+          match OscMessage::parse(&buffer[..size]) {
+            Ok(packet) => messages.lock().unwrap().extend(packet),
+            Err(err) => log::warn!("OSC packet parse error: {err}"),
+          }
+        }
+      }
+    })?;
+
+    log::info!("OSC server listening on port {port}");
+
+    Ok(Self { messages })
+  }
+}
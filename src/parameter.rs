@@ -0,0 +1,142 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Parameter {
+  Alpha,
+  Complexity,
+  Db,
+  #[serde(rename = "velocity/rotation")]
+  VelocityRotation,
+  #[serde(rename = "velocity/scaling")]
+  VelocityScaling,
+  #[serde(rename = "velocity/x")]
+  VelocityX,
+  #[serde(rename = "velocity/y")]
+  VelocityY,
+}
+
+impl Parameter {
+  fn float(value: u7) -> f32 {
+    let value = Self::integer(value);
+    if value < 0.0 {
+      value / 64.0
+    } else {
+      value / 63.0
+    }
+  }
+
+  fn from_float(value: f32) -> u7 {
+    Self::from_integer(if value < 0.0 {
+      value * 64.0
+    } else {
+      value * 63.0
+    })
+  }
+
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  fn from_integer(value: f32) -> u7 {
+    u7::new((value.round() + 64.0).clamp(0.0, 127.0) as u8)
+  }
+
+  pub(crate) fn from_path(path: &str) -> Option<Self> {
+    match path {
+      "alpha" => Some(Self::Alpha),
+      "complexity" => Some(Self::Complexity),
+      "db" => Some(Self::Db),
+      "velocity/rotation" => Some(Self::VelocityRotation),
+      "velocity/scaling" => Some(Self::VelocityScaling),
+      "velocity/x" => Some(Self::VelocityX),
+      "velocity/y" => Some(Self::VelocityY),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn get(self, state: &State) -> u7 {
+    match self {
+      Self::Alpha => Self::from_float(state.alpha * 2.0 - 1.0),
+      Self::Complexity => Self::from_float(state.complexity),
+      Self::Db => Self::from_integer(state.db),
+      Self::VelocityRotation => Self::from_float(state.velocity.w),
+      Self::VelocityScaling => Self::from_float(state.velocity.z),
+      Self::VelocityX => Self::from_float(state.velocity.x),
+      Self::VelocityY => Self::from_float(state.velocity.y),
+    }
+  }
+
+  fn integer(value: u7) -> f32 {
+    let value = i8::try_from(u8::from(value)).unwrap() - 64;
+    if value == -1 { 0.0 } else { value as f32 }
+  }
+
+  pub(crate) fn path(self) -> &'static str {
+    match self {
+      Self::Alpha => "alpha",
+      Self::Complexity => "complexity",
+      Self::Db => "db",
+      Self::VelocityRotation => "velocity/rotation",
+      Self::VelocityScaling => "velocity/scaling",
+      Self::VelocityX => "velocity/x",
+      Self::VelocityY => "velocity/y",
+    }
+  }
+
+  pub(crate) fn set(self, state: &mut State, value: f32) {
+    match self {
+      Self::Alpha => state.alpha = value,
+      Self::Complexity => state.complexity = value,
+      Self::Db => state.db = value,
+      Self::VelocityRotation => state.velocity.w = value,
+      Self::VelocityScaling => state.velocity.z = value,
+      Self::VelocityX => state.velocity.x = value,
+      Self::VelocityY => state.velocity.y = value,
+    }
+
+    state.encoder = value;
+  }
+
+  pub(crate) fn value(self, value: u7) -> f32 {
+    match self {
+      Self::Alpha => Self::float(value).midpoint(1.0),
+      Self::Db => Self::integer(value),
+      Self::Complexity
+      | Self::VelocityRotation
+      | Self::VelocityScaling
+      | Self::VelocityX
+      | Self::VelocityY => Self::float(value),
+    }
+  }
+}
+
+impl Display for Parameter {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.path())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trip() {
+    for parameter in [
+      Parameter::Alpha,
+      Parameter::Complexity,
+      Parameter::Db,
+      Parameter::VelocityRotation,
+      Parameter::VelocityScaling,
+      Parameter::VelocityX,
+      Parameter::VelocityY,
+    ] {
+      assert_eq!(Parameter::from_path(parameter.path()), Some(parameter));
+
+      for value in [0, 32, 64, 65, 127] {
+        let value = u7::new(value);
+        let mut state = State::default();
+        parameter.set(&mut state, parameter.value(value));
+        assert_eq!(parameter.get(&state), value, "{parameter}");
+      }
+    }
+  }
+}
@@ -16,6 +16,6 @@ impl Patch {
     match self {
       Self::Saw => tap.sequence(saw_hz(frequency) * 0.25, 0.3, 0.05, 0.05),
       Self::Sine => tap.sequence(sine_hz(frequency) * 0.25, 0.3, 0.05, 0.05),
-    }
+    };
   }
 }
@@ -0,0 +1,14 @@
+use {super::*, fundsp::sequencer::EventId};
+
+pub(crate) struct Playback {
+  pub(crate) id: EventId,
+  pub(crate) looping: Option<Range<usize>>,
+  pub(crate) start: f64,
+  pub(crate) wave: Arc<Wave>,
+}
+
+impl Playback {
+  pub(crate) fn end(&self) -> f64 {
+    self.start + self.wave.duration()
+  }
+}
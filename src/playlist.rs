@@ -0,0 +1,49 @@
+use super::*;
+
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Playlist {
+  pub(crate) tracks: Vec<Utf8PathBuf>,
+}
+
+impl Playlist {
+  pub(crate) fn load(path: &Utf8Path) -> Result<Self> {
+    let m3u = fs::read_to_string(path).context(error::FilesystemIo { path })?;
+    Ok(Self::parse(
+      path.parent().unwrap_or(Utf8Path::new("")),
+      &m3u,
+    ))
+  }
+
+  fn parse(base: &Utf8Path, m3u: &str) -> Self {
+    Self {
+      tracks: m3u
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| base.join(line))
+        .collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    assert_eq!(
+      Playlist::parse(
+        "music".into(),
+        "#EXTM3U\n#EXTINF:123,Artist - Title\na.flac\n\n  /b.wav  \r\nsub/c.mp3\n",
+      ),
+      Playlist {
+        tracks: vec![
+          "music/a.flac".into(),
+          "/b.wav".into(),
+          "music/sub/c.mp3".into()
+        ],
+      },
+    );
+  }
+}
@@ -36,6 +36,27 @@ impl Position {
     self.quarter.is_multiple_of(32)
   }
 
+  pub(crate) fn parse(text: &str) -> Option<Self> {
+    let mut components = text.split('.').map(|component| {
+      component
+        .parse::<u64>()
+        .ok()
+        .filter(|&component| component > 0)
+    });
+
+    match (
+      components.next(),
+      components.next(),
+      components.next(),
+      components.next(),
+    ) {
+      (Some(Some(bar)), Some(Some(beat)), Some(Some(quarter)), None) => {
+        Some(bbq(bar, beat, quarter))
+      }
+      _ => None,
+    }
+  }
+
   pub(crate) const fn quarter(self) -> u64 {
     self.quarter
   }
@@ -0,0 +1,57 @@
+use super::*;
+
+pub(crate) struct Preload {
+  handle: JoinHandle<Option<(usize, Arc<Wave>, Option<Tempo>)>>,
+  index: usize,
+}
+
+impl Preload {
+  pub(crate) fn index(&self) -> usize {
+    self.index
+  }
+
+  pub(crate) fn join(
+    self,
+    playlist: &[Utf8PathBuf],
+    sample_rate: u32,
+  ) -> Option<(usize, Arc<Wave>, Option<Tempo>)> {
+    match self.handle.join() {
+      Ok(track) => track,
+      Err(_panic_value) => {
+        log::warn!("preload thread panicked, loading synchronously");
+        Self::load(playlist, self.index, sample_rate)
+      }
+    }
+  }
+
+  pub(crate) fn load(
+    playlist: &[Utf8PathBuf],
+    mut index: usize,
+    sample_rate: u32,
+  ) -> Option<(usize, Arc<Wave>, Option<Tempo>)> {
+    while let Some(path) = playlist.get(index) {
+      match Tap::decode(path, sample_rate) {
+        Ok(wave) => {
+          let tempo = Tempo::load(path, &wave)
+            .inspect_err(|err| log::warn!("failed to load tempo for {path}: {err}"))
+            .ok();
+
+          return Some((index, wave, tempo));
+        }
+        Err(err) => {
+          log::warn!("failed to load {path}: {err}");
+          index += 1;
+        }
+      }
+    }
+
+    None
+  }
+
+  pub(crate) fn new(playlist: Vec<Utf8PathBuf>, index: usize, sample_rate: u32) -> Result<Self> {
+    Ok(Self {
+      handle: thread_spawn("preload", move || Self::load(&playlist, index, sample_rate))?,
+      index,
+    })
+  }
+}
@@ -15,8 +15,20 @@ const GRIDS: &[Mat3x2f] = &[
 ];
 
 #[derive(
-  Clone, Copy, Debug, EnumIter, ValueEnum, IntoStaticStr, PartialEq, Ord, PartialOrd, Eq,
+  Clone,
+  Copy,
+  Debug,
+  Deserialize,
+  EnumIter,
+  Eq,
+  IntoStaticStr,
+  Ord,
+  PartialEq,
+  PartialOrd,
+  Serialize,
+  ValueEnum,
 )]
+#[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub(crate) enum Preset {
   Circle,
@@ -1,5 +1,9 @@
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum Press {
+  #[default]
   Press,
   Release,
 }
@@ -0,0 +1,115 @@
+use super::*;
+
+pub(crate) struct Readback {
+  depth: usize,
+  submissions: VecDeque<SubmissionIndex>,
+}
+
+impl Readback {
+  pub(crate) const DEPTH: usize = 4;
+
+  pub(crate) fn capture(
+    &mut self,
+    renderer: &Renderer,
+    callback: impl FnOnce(Image) + Send + 'static,
+  ) -> Result {
+    self.submissions.push_back(renderer.capture(callback)?);
+
+    while self.submissions.len() > self.depth {
+      renderer.poll_submission(self.submissions.pop_front().unwrap())?;
+    }
+
+    renderer.poll_nonblocking()
+  }
+
+  pub(crate) fn finish(mut self, renderer: &Renderer) -> Result {
+    if let Some(submission) = self.submissions.pop_back() {
+      renderer.poll_submission(submission)?;
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn new(depth: usize) -> Self {
+    Self {
+      depth,
+      submissions: VecDeque::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[ignore]
+  fn pipelined() {
+    fn frames(depth: usize) -> Vec<(u64, Image)> {
+      let options = Options {
+        preset: Some(vec![Preset::Circle, Preset::RotateRedResponsive]),
+        resolution: Some(64.try_into().unwrap()),
+        score: Some(Score::PinkNoise),
+        seed: Some(0),
+        ..default()
+      };
+
+      let fps = Fps::from(DEFAULT_FPS);
+
+      let mut tap = Tap::new(&options, DEFAULT_SAMPLE_RATE);
+
+      options.add_source(&Config::default(), &mut tap).unwrap();
+
+      let mut samples = vec![0.0; fps.spf(tap.format()).unwrap()];
+
+      let mut simulation = Simulation::new(&options, &Config::default(), None).unwrap();
+
+      let mut renderer = options.renderer().unwrap();
+
+      let mut readback = Readback::new(depth);
+
+      let (tx, rx) = mpsc::channel();
+
+      for frame in 0..30 {
+        let done = tap.is_done();
+
+        tap.write(&mut samples);
+
+        let sound = tap.drain();
+
+        let tick = tap.tick(fps.dt());
+
+        simulation.update(&sound, done, tick).unwrap();
+
+        renderer
+          .render(&simulation.analyzer, &simulation.state, None)
+          .unwrap();
+
+        let tx = tx.clone();
+        readback
+          .capture(&renderer, move |image| tx.send((frame, image)).unwrap())
+          .unwrap();
+
+        simulation.tick(tick).unwrap();
+      }
+
+      readback.finish(&renderer).unwrap();
+
+      renderer.finish().unwrap();
+
+      drop(tx);
+
+      let mut frames = rx.iter().collect::<Vec<(u64, Image)>>();
+
+      frames.sort_by_key(|(frame, _image)| *frame);
+
+      frames
+    }
+
+    let serial = frames(0);
+
+    assert_eq!(serial.len(), 30);
+
+    assert!(serial == frames(Readback::DEPTH));
+  }
+}
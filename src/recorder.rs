@@ -5,21 +5,23 @@ use {
 
 pub(crate) struct Recorder {
   audio: Sound,
-  encoder: Child,
+  encoder: Option<(Child, BufWriter<ChildStdin>)>,
   end: Option<u64>,
+  format: VideoFormat,
   frames: HashMap<u64, (Image, Sound)>,
   frames_encoded: u64,
   heap: BinaryHeap<Reverse<u64>>,
   size: Size,
   spf: usize,
-  stdin: BufWriter<ChildStdin>,
   #[allow(unused)]
   tempdir: TempDir,
   tempdir_path: Utf8PathBuf,
 }
 
 impl Recorder {
-  const VIDEO: &str = "video.mp4";
+  const FRAMES: &str = "frames";
+  const RECORDING: &str = "recording";
+  const VIDEO: &str = "video";
 
   fn encoders() -> Result<BTreeSet<String>> {
     let output = Command::new("ffmpeg")
@@ -54,7 +56,7 @@ impl Recorder {
     Ok(encoders)
   }
 
-  pub(crate) fn finish(mut self, options: &Options, config: &Config, stem: Option<&str>) -> Result {
+  pub(crate) fn finish(self, options: &Options, config: &Config, stem: Option<&str>) -> Result {
     assert!(self.heap.is_empty());
 
     let frame_imbalance = self.frame_imbalance();
@@ -63,38 +65,66 @@ impl Recorder {
       log::warn!("frame imbalance: {frame_imbalance:+}");
     }
 
-    self.stdin.flush().context(error::RecordingFlush)?;
-    drop(self.stdin);
+    if let Some((encoder, mut stdin)) = self.encoder {
+      stdin.flush().context(error::RecordingFlush)?;
+      drop(stdin);
 
-    let output = self
-      .encoder
-      .wait_with_output()
-      .context(error::RecordingWait)?;
+      let output = encoder.wait_with_output().context(error::RecordingWait)?;
 
-    Self::process_output(&output)?;
+      Self::process_output(&output)?;
+    }
 
-    self.audio.save(&self.tempdir_path.join(AUDIO))?;
+    let Some(extension) = self.format.extension() else {
+      let frames = self.tempdir_path.join(Self::FRAMES);
 
-    let output = Command::new("ffmpeg")
-      .arg("-hide_banner")
-      .args(["-i", Self::VIDEO])
-      .args(["-i", AUDIO])
-      .args(["-c:v", "copy"])
-      .args(["-movflags", "+faststart"])
-      .args(["-c:a", "aac"])
-      .arg(RECORDING)
-      .current_dir(&self.tempdir_path)
-      .stderr(options.stdio())
-      .stdout(options.stdio())
-      .new_process_group()
-      .output()
-      .context(error::RecordingInvoke)?;
+      self.audio.save(&frames.join(AUDIO))?;
 
-    Self::process_output(&output)?;
+      let path = config.capture(stem, None);
+
+      fs::rename(frames, &path).context(error::FilesystemIo { path })?;
 
-    let path = config.capture(stem, "mp4");
+      return Ok(());
+    };
+
+    let video = format!("{}.{extension}", Self::VIDEO);
+
+    let recording = if let Some(audio_codec) = self.format.audio_codec() {
+      self.audio.save(&self.tempdir_path.join(AUDIO))?;
 
-    fs::rename(self.tempdir_path.join(RECORDING), &path).context(error::FilesystemIo { path })?;
+      let recording = format!("{}.{extension}", Self::RECORDING);
+
+      let mut command = Command::new("ffmpeg");
+
+      command
+        .arg("-hide_banner")
+        .args(["-i", &video])
+        .args(["-i", AUDIO])
+        .args(["-c:v", "copy"]);
+
+      if self.format.faststart() {
+        command.args(["-movflags", "+faststart"]);
+      }
+
+      let output = command
+        .args(["-c:a", audio_codec])
+        .arg(&recording)
+        .current_dir(&self.tempdir_path)
+        .stderr(options.stdio())
+        .stdout(options.stdio())
+        .new_process_group()
+        .output()
+        .context(error::RecordingInvoke)?;
+
+      Self::process_output(&output)?;
+
+      recording
+    } else {
+      video
+    };
+
+    let path = config.capture(stem, Some(extension));
+
+    fs::rename(self.tempdir_path.join(recording), &path).context(error::FilesystemIo { path })?;
 
     Ok(())
   }
@@ -145,10 +175,17 @@ impl Recorder {
       let Reverse(frame) = self.heap.pop().unwrap();
       assert_eq!(frame, self.frames_encoded);
       let (image, sound) = self.frames.remove(&frame).unwrap();
-      self
-        .stdin
-        .write_all(image.data())
-        .context(error::RecordingWrite)?;
+      match &mut self.encoder {
+        Some((_encoder, stdin)) => stdin
+          .write_all(image.data())
+          .context(error::RecordingWrite)?,
+        None => image.save(
+          &self
+            .tempdir_path
+            .join(Self::FRAMES)
+            .join(format!("{frame:06}.png")),
+        )?,
+      }
       self.audio.append(sound).unwrap();
       self.frames_encoded += 1;
     }
@@ -168,6 +205,7 @@ impl Recorder {
 
   pub(crate) fn new(
     fps: Fps,
+    format: VideoFormat,
     options: &Options,
     realtime: bool,
     size: Size,
@@ -175,57 +213,52 @@ impl Recorder {
   ) -> Result<Self> {
     let (tempdir, tempdir_path) = tempdir()?;
 
-    let encoders = Self::encoders()?;
-
-    let encoder_options: &[[&str; 2]] = if encoders.contains("h264_videotoolbox") {
-      &[
-        ["-c:v", "h264_videotoolbox"],
-        ["-q:v", if realtime { "100" } else { "90" }],
-        ["-realtime", "true"],
-      ]
+    let encoder = if let Some(extension) = format.extension() {
+      let mut encoder = Command::new("ffmpeg")
+        .arg("-hide_banner")
+        .args(["-f", "rawvideo"])
+        .args(["-color_primaries", "bt709"])
+        .args(["-color_range", "pc"])
+        .args(["-color_trc", "bt709"])
+        .args(["-colorspace", "bt709"])
+        .args(["-framerate", &fps.to_string()])
+        .args(["-pixel_format", "rgba"])
+        .args(["-video_size", &format!("{}x{}", size.x, size.y)])
+        .args(["-i", "-"])
+        .args(
+          format
+            .video_arguments(&Self::encoders()?, realtime)
+            .iter()
+            .flatten(),
+        )
+        .arg(format!("{}.{extension}", Self::VIDEO))
+        .current_dir(&tempdir_path)
+        .stdin(Stdio::piped())
+        .stderr(options.stdio())
+        .stdout(options.stdio())
+        .new_process_group()
+        .spawn()
+        .context(error::RecordingInvoke)?;
+
+      let stdin = BufWriter::new(encoder.stdin.take().unwrap());
+
+      Some((encoder, stdin))
     } else {
-      &[["-c:v", "libx264"], ["-crf", "18"], ["-preset", "slow"]]
+      let frames = tempdir_path.join(Self::FRAMES);
+      fs::create_dir(&frames).context(error::FilesystemIo { path: frames })?;
+      None
     };
 
-    let mut encoder = Command::new("ffmpeg")
-      .arg("-hide_banner")
-      .args(["-f", "rawvideo"])
-      .args(["-color_primaries", "bt709"])
-      .args(["-color_range", "pc"])
-      .args(["-color_trc", "bt709"])
-      .args(["-colorspace", "bt709"])
-      .args(["-framerate", &fps.to_string()])
-      .args(["-pixel_format", "rgba"])
-      .args(["-video_size", &format!("{}x{}", size.x, size.y)])
-      .args(["-i", "-"])
-      .args(encoder_options.iter().flatten())
-      .args(["-color_primaries", "bt709"])
-      .args(["-color_range", "pc"])
-      .args(["-color_trc", "bt709"])
-      .args(["-colorspace", "bt709"])
-      .args(["-level:v", if realtime { "5.2" } else { "5.1" }])
-      .args(["-pix_fmt", "yuv420p"])
-      .arg(Self::VIDEO)
-      .current_dir(&tempdir_path)
-      .stdin(Stdio::piped())
-      .stderr(options.stdio())
-      .stdout(options.stdio())
-      .new_process_group()
-      .spawn()
-      .context(error::RecordingInvoke)?;
-
-    let stdin = BufWriter::new(encoder.stdin.take().unwrap());
-
     Ok(Self {
       audio: Sound::empty(sound_format),
       encoder,
       end: None,
+      format,
       frames: HashMap::new(),
       frames_encoded: 0,
       heap: BinaryHeap::new(),
       size,
       spf: fps.spf(sound_format)?,
-      stdin,
       tempdir,
       tempdir_path,
     })
@@ -2,15 +2,17 @@ use super::*;
 
 pub(crate) struct RecorderThread {
   handle: JoinHandle<Result<Recorder>>,
-  tx: mpsc::Sender<(u64, Image, Sound)>,
+  tx: mpsc::SyncSender<(u64, Image, Sound)>,
 }
 
 impl RecorderThread {
-  pub(crate) fn finish(self, options: &Options, config: &Config) -> Result {
+  const DEPTH: usize = 8;
+
+  pub(crate) fn finish(self, options: &Options, config: &Config, stem: Option<&str>) -> Result {
     drop(self.tx);
 
     match self.handle.join() {
-      Ok(Ok(recorder)) => recorder.finish(options, config, None),
+      Ok(Ok(recorder)) => recorder.finish(options, config, stem),
       Ok(Err(err)) => Err(err),
       Err(panic_value) => Err(error::RecordingJoin { panic_value }.build()),
     }
@@ -21,14 +23,10 @@ impl RecorderThread {
   }
 
   pub(crate) fn new(mut recorder: Recorder) -> Result<Self> {
-    let (tx, rx) = mpsc::channel();
+    let (tx, rx) = mpsc::sync_channel(Self::DEPTH);
 
     let handle = thread_spawn("recorder", move || {
-      loop {
-        let Ok((frame, image, sound)) = rx.recv() else {
-          break;
-        };
-
+      while let Ok((frame, image, sound)) = rx.recv() {
         recorder.frame(frame, image, sound)?;
       }
 
@@ -38,7 +36,7 @@ impl RecorderThread {
     Ok(Self { handle, tx })
   }
 
-  pub(crate) fn tx(&self) -> &mpsc::Sender<(u64, Image, Sound)> {
+  pub(crate) fn tx(&self) -> &mpsc::SyncSender<(u64, Image, Sound)> {
     &self.tx
   }
 }
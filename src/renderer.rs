@@ -20,6 +20,7 @@ pub(crate) struct Renderer {
   format: ImageFormat,
   frame: u64,
   frequencies: TextureView,
+  history_row: u64,
   layout_context: LayoutContext,
   limits: Limits,
   mirroring_sampler: Sampler,
@@ -29,9 +30,13 @@ pub(crate) struct Renderer {
   resources: Option<Resources>,
   samples: TextureView,
   size: Size,
+  spectrogram: TextureView,
+  stereo_frequencies: TextureView,
+  stereo_samples: TextureView,
   surface: Option<(Surface<'static>, SurfaceConfiguration)>,
   vello_renderer: vello::Renderer,
   vello_scene: vello::Scene,
+  waveforms: TextureView,
 }
 
 impl Renderer {
@@ -72,7 +77,10 @@ impl Renderer {
     (self.resolution.get() * u32::try_from(COLOR_CHANNELS).unwrap() + MASK) & !MASK
   }
 
-  pub(crate) fn capture(&self, callback: impl FnOnce(Image) + Send + 'static) -> Result {
+  pub(crate) fn capture(
+    &self,
+    callback: impl FnOnce(Image) + Send + 'static,
+  ) -> Result<SubmissionIndex> {
     let bytes_per_row_with_padding = self.bytes_per_row_with_padding();
 
     let mut encoder = self
@@ -113,7 +121,7 @@ impl Renderer {
       },
     );
 
-    self.queue.submit([encoder.finish()]);
+    let submission = self.queue.submit([encoder.finish()]);
 
     let capture = Capture {
       buffer: buffer.clone(),
@@ -133,7 +141,7 @@ impl Renderer {
       tx.send(capture).ok();
     });
 
-    Ok(())
+    Ok(submission)
   }
 
   fn clamp_resolution(limits: &Limits, resolution: NonZeroU32) -> NonZeroU32 {
@@ -417,6 +425,22 @@ impl Renderer {
           binding: binding.next(),
           resource: BindingResource::TextureView(samples),
         },
+        BindGroupEntry {
+          binding: binding.next(),
+          resource: BindingResource::TextureView(&self.spectrogram),
+        },
+        BindGroupEntry {
+          binding: binding.next(),
+          resource: BindingResource::TextureView(&self.stereo_frequencies),
+        },
+        BindGroupEntry {
+          binding: binding.next(),
+          resource: BindingResource::TextureView(&self.stereo_samples),
+        },
+        BindGroupEntry {
+          binding: binding.next(),
+          resource: BindingResource::TextureView(&self.waveforms),
+        },
         BindGroupEntry {
           binding: binding.next(),
           resource: BindingResource::Buffer(BufferBinding {
@@ -486,6 +510,46 @@ impl Renderer {
           },
           visibility: ShaderStages::FRAGMENT,
         },
+        BindGroupLayoutEntry {
+          binding: binding.next(),
+          count: None,
+          ty: BindingType::Texture {
+            multisampled: false,
+            sample_type: TextureSampleType::Float { filterable: false },
+            view_dimension: TextureViewDimension::D2,
+          },
+          visibility: ShaderStages::FRAGMENT,
+        },
+        BindGroupLayoutEntry {
+          binding: binding.next(),
+          count: None,
+          ty: BindingType::Texture {
+            multisampled: false,
+            sample_type: TextureSampleType::Float { filterable: false },
+            view_dimension: TextureViewDimension::D2,
+          },
+          visibility: ShaderStages::FRAGMENT,
+        },
+        BindGroupLayoutEntry {
+          binding: binding.next(),
+          count: None,
+          ty: BindingType::Texture {
+            multisampled: false,
+            sample_type: TextureSampleType::Float { filterable: false },
+            view_dimension: TextureViewDimension::D2,
+          },
+          visibility: ShaderStages::FRAGMENT,
+        },
+        BindGroupLayoutEntry {
+          binding: binding.next(),
+          count: None,
+          ty: BindingType::Texture {
+            multisampled: false,
+            sample_type: TextureSampleType::Float { filterable: false },
+            view_dimension: TextureViewDimension::D2,
+          },
+          visibility: ShaderStages::FRAGMENT,
+        },
         BindGroupLayoutEntry {
           binding: binding.next(),
           count: None,
@@ -737,6 +801,74 @@ impl Renderer {
       })
       .create_view(&TextureViewDescriptor::default());
 
+    let stereo_samples = device
+      .create_texture(&TextureDescriptor {
+        dimension: TextureDimension::D2,
+        format: TextureFormat::R32Float,
+        label: label!(),
+        mip_level_count: 1,
+        sample_count: 1,
+        size: Extent3d {
+          depth_or_array_layers: 1,
+          height: 2,
+          width: limits.max_texture_dimension_1d,
+        },
+        usage: TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[TextureFormat::R32Float],
+      })
+      .create_view(&TextureViewDescriptor::default());
+
+    let stereo_frequencies = device
+      .create_texture(&TextureDescriptor {
+        dimension: TextureDimension::D2,
+        format: TextureFormat::R32Float,
+        label: label!(),
+        mip_level_count: 1,
+        sample_count: 1,
+        size: Extent3d {
+          depth_or_array_layers: 1,
+          height: 2,
+          width: limits.max_texture_dimension_1d,
+        },
+        usage: TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[TextureFormat::R32Float],
+      })
+      .create_view(&TextureViewDescriptor::default());
+
+    let spectrogram = device
+      .create_texture(&TextureDescriptor {
+        dimension: TextureDimension::D2,
+        format: TextureFormat::R32Float,
+        label: label!(),
+        mip_level_count: 1,
+        sample_count: 1,
+        size: Extent3d {
+          depth_or_array_layers: 1,
+          height: Analyzer::HISTORY,
+          width: limits.max_texture_dimension_1d,
+        },
+        usage: TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[TextureFormat::R32Float],
+      })
+      .create_view(&TextureViewDescriptor::default());
+
+    let waveforms = device
+      .create_texture(&TextureDescriptor {
+        dimension: TextureDimension::D2,
+        format: TextureFormat::R32Float,
+        label: label!(),
+        mip_level_count: 1,
+        sample_count: 1,
+        size: Extent3d {
+          depth_or_array_layers: 1,
+          height: Analyzer::HISTORY,
+          width: limits.max_texture_dimension_1d,
+        },
+        usage: TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[TextureFormat::R32Float],
+      })
+      .create_view(&TextureViewDescriptor::default());
+
     let vello_renderer = vello::Renderer::new(
       &device,
       vello::RendererOptions {
@@ -764,6 +896,7 @@ impl Renderer {
       format,
       frame: 0,
       frequencies,
+      history_row: 0,
       layout_context: LayoutContext::new(),
       limits,
       mirroring_sampler,
@@ -773,9 +906,13 @@ impl Renderer {
       resources: None,
       samples,
       size,
+      spectrogram,
+      stereo_frequencies,
+      stereo_samples,
       surface,
       vello_renderer,
       vello_scene: vello::Scene::new(),
+      waveforms,
     };
 
     renderer.resize(size, resolution);
@@ -784,9 +921,21 @@ impl Renderer {
   }
 
   pub(crate) fn poll(&self) -> Result {
+    self.poll_type(wgpu::PollType::Wait)
+  }
+
+  pub(crate) fn poll_nonblocking(&self) -> Result {
+    self.poll_type(wgpu::PollType::Poll)
+  }
+
+  pub(crate) fn poll_submission(&self, submission: SubmissionIndex) -> Result {
+    self.poll_type(wgpu::PollType::WaitForSubmissionIndex(submission))
+  }
+
+  fn poll_type(&self, poll_type: wgpu::PollType) -> Result {
     self
       .device
-      .poll(wgpu::PollType::Wait)
+      .poll(poll_type)
       .map(|_poll_status| ())
       .context(error::RenderPoll)
   }
@@ -886,7 +1035,24 @@ impl Renderer {
       &analyzer.samples()[..sample_count]
     };
     let sample_range = sample_count as f32 / self.samples.texture().width() as f32;
-    self.write_texture(samples, self.samples.texture());
+    self.write_texture(samples, 0, 1, self.samples.texture());
+
+    self.update_history(analyzer);
+
+    let history = (self.history_row % u64::from(Analyzer::HISTORY)) as f32 + 0.5;
+    let history = history / Analyzer::HISTORY as f32;
+
+    let stereo_samples = if sample_count == 0 {
+      vec![0.0; 2]
+    } else {
+      analyzer
+        .stereo_samples()
+        .iter()
+        .flat_map(|samples| &samples[..sample_count])
+        .copied()
+        .collect()
+    };
+    self.write_texture(&stereo_samples, 0, 2, self.stereo_samples.texture());
 
     let frequency_count = analyzer
       .frequencies()
@@ -898,7 +1064,19 @@ impl Renderer {
       &analyzer.frequencies()[..frequency_count]
     };
     let frequency_range = frequency_count as f32 / self.frequencies.texture().width() as f32;
-    self.write_texture(frequencies, self.frequencies.texture());
+    self.write_texture(frequencies, 0, 1, self.frequencies.texture());
+
+    let stereo_frequencies = if frequency_count == 0 {
+      vec![0.0; 2]
+    } else {
+      analyzer
+        .stereo_frequencies()
+        .iter()
+        .flat_map(|frequencies| &frequencies[..frequency_count])
+        .copied()
+        .collect()
+    };
+    self.write_texture(&stereo_frequencies, 0, 2, self.stereo_frequencies.texture());
 
     let filter_count = u32::try_from(filters).unwrap();
 
@@ -912,6 +1090,12 @@ impl Renderer {
       for (i, filter) in state.filters.iter().chain(&transient).enumerate() {
         let i = u32::try_from(i).unwrap();
 
+        let rms = match filter.source {
+          Source::Band(band) => analyzer.band(band),
+          Source::Rms => rms,
+          Source::Width => analyzer.width(),
+        };
+
         let rms = if state.spread {
           rms * (i as f32 + 1.0) / filters as f32
         } else {
@@ -920,7 +1104,7 @@ impl Renderer {
 
         let rms = rms * filter.rms[0] + filter.rms[1];
 
-        let response = rms / 10.0 * gain;
+        let response = rms * gain;
 
         uniforms.push(FilterUniforms {
           alpha: filter.alpha,
@@ -934,6 +1118,7 @@ impl Renderer {
           gain,
           grid: filter.grid,
           grid_transform: filter.grid_transform,
+          history,
           interpolate: state.interpolate,
           mirror: filter.mirror_uniform(),
           parameter: filter.field.parameter(),
@@ -1499,12 +1684,42 @@ impl Renderer {
     }
   }
 
-  fn write_texture(&self, data: &[f32], destination: &Texture) {
+  fn update_history(&mut self, analyzer: &Analyzer) {
+    let row = analyzer.row();
+
+    let oldest = row.saturating_sub(u64::from(Analyzer::HISTORY) - 1);
+
+    for row in (self.history_row + 1).max(oldest)..=row {
+      let Some((frequencies, samples)) = analyzer.history(row) else {
+        continue;
+      };
+
+      let index = u32::try_from(row % u64::from(Analyzer::HISTORY)).unwrap();
+
+      for (data, texture) in [
+        (frequencies, self.spectrogram.texture()),
+        (samples, self.waveforms.texture()),
+      ] {
+        let data = &data[..data.len().min(texture.width().into_usize())];
+        self.write_texture(
+          if data.is_empty() { &[0.0] } else { data },
+          index,
+          1,
+          texture,
+        );
+      }
+    }
+
+    self.history_row = row;
+  }
+
+  fn write_texture(&self, data: &[f32], row: u32, rows: u32, destination: &Texture) {
+    let width = u32::try_from(data.len()).unwrap() / rows;
     self.queue.write_texture(
       TexelCopyTextureInfo {
         texture: destination,
         mip_level: 0,
-        origin: Origin3d::ZERO,
+        origin: Origin3d { x: 0, y: row, z: 0 },
         aspect: TextureAspect::All,
       },
       &data
@@ -1513,12 +1728,12 @@ impl Renderer {
         .collect::<Vec<u8>>(),
       TexelCopyBufferLayout {
         offset: 0,
-        bytes_per_row: None,
+        bytes_per_row: Some(width * 4),
         rows_per_image: None,
       },
       Extent3d {
-        width: data.len().try_into().unwrap(),
-        height: 1,
+        width,
+        height: rows,
         depth_or_array_layers: 1,
       },
     );
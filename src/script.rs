@@ -1,4 +1,23 @@
-use super::*;
+use {super::*, position::bars};
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Error)), visibility(pub(crate)))]
+pub(crate) enum ScriptParseError {
+  #[snafu(display("line {line}: command `{name}` takes arguments"))]
+  Arguments { line: usize, name: String },
+  #[snafu(display("line {line}: unknown command `{name}`"))]
+  Command { line: usize, name: String },
+  #[snafu(display("line {line}: expected {expected}"))]
+  Expected { line: usize, expected: &'static str },
+  #[snafu(display("line {line}: invalid position `{text}`"))]
+  Position { line: usize, text: String },
+  #[snafu(display("line {line}: invalid bar range `{text}`"))]
+  Range { line: usize, text: String },
+  #[snafu(display("line {line}: invalid step `{text}`"))]
+  Step { line: usize, text: String },
+  #[snafu(display("line {line}: unexpected token `{token}`"))]
+  Trailing { line: usize, token: String },
+}
 
 #[derive(Debug, Default)]
 pub(crate) struct Script {
@@ -22,6 +41,11 @@ impl Script {
     self.commands.values().flatten().copied()
   }
 
+  pub(crate) fn load(path: &Utf8Path) -> Result<Self> {
+    let src = fs::read_to_string(path).context(error::FilesystemIo { path })?;
+    Self::parse(&src).context(error::ScriptParse { path })
+  }
+
   pub(crate) fn new() -> Self {
     Self::default()
   }
@@ -39,6 +63,130 @@ impl Script {
       .push(entry);
   }
 
+  pub(crate) fn parse(src: &str) -> Result<Self, ScriptParseError> {
+    fn position(line: usize, text: &str) -> Result<Position, ScriptParseError> {
+      Position::parse(text).context(PositionError { line, text })
+    }
+
+    fn range(text: &str) -> Option<Range<u64>> {
+      let (start, end) = text.split_once("..")?;
+      let start = start.parse::<u64>().ok().filter(|&start| start > 0)?;
+      let end = end.parse::<u64>().ok()?;
+      Some(start..end)
+    }
+
+    let commands = Commands::new();
+
+    let mut script = Self::new();
+
+    for (i, text) in src.lines().enumerate() {
+      let line = i + 1;
+
+      let text = text.split_once('#').map_or(text, |(text, _comment)| text);
+
+      let mut tokens = text.split_whitespace().peekable();
+
+      if tokens.peek().is_none() {
+        continue;
+      }
+
+      let bars = if tokens.next_if_eq(&"bars").is_some() {
+        let mut ranges = Vec::new();
+
+        while let Some(token) = tokens.next_if(|token| token.contains("..")) {
+          ranges.push(range(token).context(RangeError { line, text: token })?);
+        }
+
+        ensure!(
+          !ranges.is_empty(),
+          ExpectedError {
+            line,
+            expected: "bar range",
+          }
+        );
+
+        let step = if tokens.next_if_eq(&"step").is_some() {
+          let token = tokens.next().context(ExpectedError {
+            line,
+            expected: "step",
+          })?;
+
+          token
+            .parse::<usize>()
+            .ok()
+            .filter(|&step| step > 0)
+            .context(StepError { line, text: token })?
+        } else {
+          1
+        };
+
+        ranges
+          .into_iter()
+          .flat_map(bars)
+          .step_by(step)
+          .collect::<Vec<Position>>()
+      } else {
+        vec![Position::from_quarter(0)]
+      };
+
+      if tokens.next_if_eq(&"clear").is_some() {
+        let token = tokens.next().context(ExpectedError {
+          line,
+          expected: "position range",
+        })?;
+
+        let (start, end) = token
+          .split_once("..")
+          .context(PositionError { line, text: token })?;
+
+        let start = position(line, start)?;
+        let end = position(line, end)?;
+
+        for &bar in &bars {
+          script.clear(Bound::Included(bar + start), Bound::Excluded(bar + end));
+        }
+      } else {
+        let token = tokens.next().context(ExpectedError {
+          line,
+          expected: "position",
+        })?;
+
+        let offset = position(line, token)?;
+
+        let only = tokens.next_if_eq(&"only").is_some();
+
+        let name = tokens.next().context(ExpectedError {
+          line,
+          expected: "command",
+        })?;
+
+        let entry = commands.name(name).context(CommandError { line, name })?;
+
+        ensure!(
+          !entry.command.takes_arguments(),
+          ArgumentsError { line, name },
+        );
+
+        for &bar in &bars {
+          if only {
+            script.only(bar + offset, entry);
+          } else {
+            script.on(bar + offset, entry);
+          }
+        }
+      }
+
+      if let Some(token) = tokens.next() {
+        return Err(ScriptParseError::Trailing {
+          line,
+          token: token.into(),
+        });
+      }
+    }
+
+    Ok(script)
+  }
+
   pub(crate) fn tick(&self, tick: Tick) -> &[CommandEntry] {
     let Some(position) = tick.advance() else {
       return default();
@@ -64,3 +212,78 @@ impl Display for Script {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    generated::*,
+    position::{bar, beat, quarter},
+  };
+
+  #[test]
+  fn errors() {
+    #[track_caller]
+    fn case(src: &str, expected: &str) {
+      assert_eq!(Script::parse(src).unwrap_err().to_string(), expected);
+    }
+
+    case("1.1.1 foo", "line 1: unknown command `foo`");
+    case("1.1.1 db", "line 1: command `db` takes arguments");
+    case("\n1.1 clear", "line 2: invalid position `1.1`");
+    case("0.1.1 clear", "line 1: invalid position `0.1.1`");
+    case("1.1.1", "line 1: expected command");
+    case("bars 1.1.1 clear", "line 1: expected bar range");
+    case("bars 1..x 1.1.1 clear", "line 1: invalid bar range `1..x`");
+    case("bars 1..3 step 0 1.1.1 clear", "line 1: invalid step `0`");
+    case("bars 1..3 clear 1.1.1", "line 1: invalid position `1.1.1`");
+    case(
+      "# comment\n\n1.1.1 clear pop",
+      "line 3: unexpected token `pop`",
+    );
+  }
+
+  #[test]
+  fn parse() {
+    let mut expected = Script::new();
+
+    expected.on(bar(39), TOGGLE_FIT);
+
+    for bar in bars(1..5) {
+      expected.on(bar + beat(1), BLASTER);
+      expected.on(bar + beat(3), BLASTER);
+    }
+
+    for bar in bars(2..4).chain(bars(7..11)).step_by(2) {
+      expected.only(bar + beat(1), ZOOM_OUT);
+      expected.only(bar + beat(5), ZOOM_OUT);
+    }
+
+    for bar in bars(3..5) {
+      expected.clear(
+        Bound::Included(bar),
+        Bound::Excluded(bar + Position::from_bar(1)),
+      );
+      expected.on(bar + beat(2) + quarter(3), PUSH_TOP);
+    }
+
+    let actual = Script::parse(
+      "
+39.1.1 toggle_fit # fit
+
+# kick
+bars 1..5 1.1.1 blaster
+bars 1..5 1.3.1 blaster
+
+bars 2..4 7..11 step 2 1.1.1 only zoom-out
+bars 2..4 7..11 step 2 1.5.1 only zoom-out
+
+bars 3..5 clear 1.1.1..2.1.1
+bars 3..5 1.2.3 push-top
+",
+    )
+    .unwrap();
+
+    assert_eq!(actual.to_string(), expected.to_string());
+  }
+}
@@ -0,0 +1,450 @@
+use {
+  super::*,
+  std::{
+    io::{BufRead, Read},
+    net::{TcpListener, TcpStream},
+  },
+  tungstenite::{WebSocket, handshake::derive_accept_key, protocol::Role},
+};
+
+pub(crate) struct Server {
+  requests: Arc<Mutex<Vec<(String, Vec<String>)>>>,
+  status: Arc<Mutex<String>>,
+}
+
+#[derive(Serialize)]
+struct CommandInfo {
+  arguments: bool,
+  name: &'static str,
+}
+
+impl Server {
+  const BODY_MAX: usize = 64 * 1024;
+  const CONNECTIONS_MAX: usize = 32;
+  const HEADERS_MAX: usize = 64;
+  const LINE_MAX: usize = 8 * 1024;
+  const POLL: Duration = Duration::from_millis(50);
+  const TIMEOUT: Duration = Duration::from_secs(5);
+
+  fn allowed(headers: &BTreeMap<String, String>, hosts: &[String]) -> bool {
+    let Some(host) = headers.get("host") else {
+      return false;
+    };
+
+    if !hosts
+      .iter()
+      .any(|allowed| allowed.eq_ignore_ascii_case(host))
+    {
+      return false;
+    }
+
+    let Some(origin) = headers.get("origin") else {
+      return true;
+    };
+
+    let origin = origin
+      .split_once("://")
+      .map_or(origin.as_str(), |(_scheme, host)| host);
+
+    host.eq_ignore_ascii_case(origin)
+  }
+
+  pub(crate) fn drain(&self) -> Vec<(String, Vec<String>)> {
+    self.requests.lock().unwrap().drain(..).collect()
+  }
+
+  fn handle(
+    stream: TcpStream,
+    commands: &str,
+    hosts: &[String],
+    requests: &Mutex<Vec<(String, Vec<String>)>>,
+    status: &Mutex<String>,
+  ) -> io::Result<()> {
+    stream.set_read_timeout(Some(Self::TIMEOUT))?;
+    stream.set_write_timeout(Some(Self::TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let Some(line) = Self::read_line(&mut reader)? else {
+      return Self::respond(stream, "414 URI Too Long", "");
+    };
+
+    let mut words = line.split_whitespace();
+    let method = words.next().unwrap_or_default().to_owned();
+    let path = words.next().unwrap_or_default().to_owned();
+
+    let mut headers = BTreeMap::new();
+
+    for count in 0.. {
+      let Some(line) = Self::read_line(&mut reader)? else {
+        return Self::respond(stream, "431 Request Header Fields Too Large", "");
+      };
+
+      let line = line.trim_end();
+
+      if line.is_empty() {
+        break;
+      }
+
+      if count == Self::HEADERS_MAX {
+        return Self::respond(stream, "431 Request Header Fields Too Large", "");
+      }
+
+      if let Some((name, value)) = line.split_once(':') {
+        headers.insert(name.trim().to_lowercase(), value.trim().to_owned());
+      }
+    }
+
+    if !Self::allowed(&headers, hosts) {
+      return Self::respond(stream, "403 Forbidden", "");
+    }
+
+    let websocket = headers
+      .get("upgrade")
+      .is_some_and(|upgrade| upgrade.eq_ignore_ascii_case("websocket"));
+
+    if websocket {
+      if method != "GET" || path != "/state" {
+        return Self::respond(stream, "404 Not Found", "");
+      }
+
+      let key = headers
+        .get("sec-websocket-key")
+        .map(String::as_str)
+        .unwrap_or_default();
+
+      write!(
+        &stream,
+        "HTTP/1.1 101 Switching Protocols\r\n\
+        Connection: Upgrade\r\n\
+        Upgrade: websocket\r\n\
+        Sec-WebSocket-Accept: {}\r\n\r\n",
+        derive_accept_key(key.as_bytes()),
+      )?;
+
+      let mut websocket = WebSocket::from_raw_socket(stream, Role::Server, None);
+
+      let mut last = String::new();
+
+      loop {
+        let current = status.lock().unwrap().clone();
+
+        if current != last {
+          if let Err(err) = websocket.send(tungstenite::Message::text(current.clone())) {
+            log::info!("WebSocket closed: {err}");
+            return Ok(());
+          }
+          last = current;
+        }
+
+        std::thread::sleep(Self::POLL);
+      }
+    }
+
+    match (method.as_str(), path.as_str()) {
+      ("GET", "/commands") => Self::respond(stream, "200 OK", commands),
+      ("GET", "/state") => {
+        let status = status.lock().unwrap().clone();
+        Self::respond(stream, "200 OK", &status)
+      }
+      ("POST", path) if path.starts_with("/commands/") => {
+        let length = headers
+          .get("content-length")
+          .and_then(|length| length.parse::<usize>().ok())
+          .unwrap_or_default();
+
+        if length > Self::BODY_MAX {
+          return Self::respond(stream, "413 Payload Too Large", "");
+        }
+
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+
+        let name = path.trim_start_matches("/commands/").to_owned();
+
+        let arguments = String::from_utf8_lossy(&body)
+          .split_whitespace()
+          .map(str::to_owned)
+          .collect();
+
+        requests.lock().unwrap().push((name, arguments));
+
+        Self::respond(stream, "202 Accepted", "")
+      }
+      ("GET" | "POST", _) => Self::respond(stream, "404 Not Found", ""),
+      _ => Self::respond(stream, "405 Method Not Allowed", ""),
+    }
+  }
+
+  pub(crate) fn new(
+    address: SocketAddr,
+    commands: &Commands,
+    hostname: Option<&str>,
+  ) -> Result<Self> {
+    let listener = TcpListener::bind(address).context(error::ServerBind { address })?;
+
+    let port = listener
+      .local_addr()
+      .context(error::ServerBind { address })?
+      .port();
+
+    let hosts = [
+      Some(address.to_string()),
+      Some(format!("localhost:{port}")),
+      Some(format!("127.0.0.1:{port}")),
+      Some(format!("[::1]:{port}")),
+      hostname.map(|hostname| format!("{hostname}:{port}")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Arc<[String]>>();
+
+    let commands = Arc::<str>::from(
+      serde_json::to_string(
+        &commands
+          .entries()
+          .map(|entry| CommandInfo {
+            arguments: entry.command.takes_arguments(),
+            name: entry.name,
+          })
+          .collect::<Vec<CommandInfo>>(),
+      )
+      .unwrap(),
+    );
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let status = Arc::new(Mutex::new(String::from("{}")));
+
+    thread_spawn("server", {
+      let connections = Arc::new(AtomicUsize::new(0));
+      let requests = requests.clone();
+      let status = status.clone();
+      move || {
+        for stream in listener.incoming() {
+          let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+              log::warn!("server connection error: {err}");
+              continue;
+            }
+          };
+
+          if connections.fetch_add(1, atomic::Ordering::Relaxed) >= Self::CONNECTIONS_MAX {
+            connections.fetch_sub(1, atomic::Ordering::Relaxed);
+            log::warn!("server connection limit reached");
+            if let Err(err) = Self::respond(stream, "503 Service Unavailable", "") {
+              log::warn!("server request error: {err}");
+            }
+            continue;
+          }
+
+          let commands = commands.clone();
+          let hosts = hosts.clone();
+          let requests = requests.clone();
+          let status = status.clone();
+
+          let result = thread_spawn("server connection", {
+            let connections = connections.clone();
+            move || {
+              if let Err(err) = Self::handle(stream, &commands, &hosts, &requests, &status) {
+                log::warn!("server request error: {err}");
+              }
+              connections.fetch_sub(1, atomic::Ordering::Relaxed);
+            }
+          });
+
+          if let Err(err) = result {
+            connections.fetch_sub(1, atomic::Ordering::Relaxed);
+            log::warn!("{err}");
+          }
+        }
+      }
+    })?;
+
+    log::info!("server listening on {address}");
+
+    Ok(Self { requests, status })
+  }
+
+  fn read_line(reader: &mut BufReader<TcpStream>) -> io::Result<Option<String>> {
+    let mut line = String::new();
+
+    reader.take(Self::LINE_MAX as u64).read_line(&mut line)?;
+
+    if line.len() == Self::LINE_MAX && !line.ends_with('\n') {
+      return Ok(None);
+    }
+
+    Ok(Some(line))
+  }
+
+  fn respond(stream: TcpStream, status: &str, body: &str) -> io::Result<()> {
+    write!(
+      &stream,
+      "HTTP/1.1 {status}\r\n\
+      Connection: close\r\n\
+      Content-Length: {}\r\n\
+      Content-Type: application/json\r\n\r\n\
+      {body}",
+      body.len(),
+    )
+  }
+
+  pub(crate) fn update(&self, status: &Status) {
+    *self.status.lock().unwrap() = serde_json::to_string(status).unwrap();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn request(address: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+  }
+
+  #[test]
+  fn server() {
+    let address = TcpListener::bind("127.0.0.1:0")
+      .unwrap()
+      .local_addr()
+      .unwrap();
+
+    let server = Server::new(address, &Commands::new(), Some("device.local")).unwrap();
+
+    let response = request(
+      address,
+      &format!("GET /commands HTTP/1.1\r\nHost: {address}\r\n\r\n"),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(
+      response.contains(r#"{"arguments":true,"name":"db"}"#),
+      "{response}",
+    );
+
+    let response = request(
+      address,
+      &format!("POST /commands/db HTTP/1.1\r\nHost: {address}\r\nContent-Length: 3\r\n\r\n-12"),
+    );
+    assert!(
+      response.starts_with("HTTP/1.1 202 Accepted\r\n"),
+      "{response}"
+    );
+    assert_eq!(server.drain(), [("db".into(), vec!["-12".into()])]);
+
+    let response = request(
+      address,
+      &format!("DELETE /state HTTP/1.1\r\nHost: {address}\r\n\r\n"),
+    );
+    assert!(response.starts_with("HTTP/1.1 405"), "{response}");
+
+    let response = request(
+      address,
+      &format!(
+        "POST /commands/db HTTP/1.1\r\nHost: {address}\r\nContent-Length: {}\r\n\r\n",
+        1 << 30
+      ),
+    );
+    assert!(response.starts_with("HTTP/1.1 413"), "{response}");
+    assert!(server.drain().is_empty());
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\n{}\r\n", "X: y\r\n".repeat(100)),
+    );
+    assert!(response.starts_with("HTTP/1.1 431"), "{response}");
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\nX: {}\r\n\r\n", "y".repeat(10_000)),
+    );
+    assert!(response.starts_with("HTTP/1.1 431"), "{response}");
+
+    let response = request(
+      address,
+      &format!(
+        "POST /commands/db HTTP/1.1\r\nHost: {address}\r\nOrigin: https://example.com\r\n\
+        Content-Length: 3\r\n\r\n-12"
+      ),
+    );
+    assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+    assert!(server.drain().is_empty());
+
+    let response = request(
+      address,
+      "POST /commands/db HTTP/1.1\r\nHost: evil.example\r\nOrigin: http://evil.example\r\n\
+      Content-Length: 3\r\n\r\n-12",
+    );
+    assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+    assert!(server.drain().is_empty());
+
+    let response = request(
+      address,
+      "POST /commands/db HTTP/1.1\r\nContent-Length: 3\r\n\r\n-12",
+    );
+    assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+    assert!(server.drain().is_empty());
+
+    let port = address.port();
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\nHost: localhost:{port}\r\n\r\n"),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\nHost: device.local:{port}\r\n\r\n"),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\nHost: {address}\r\nOrigin: http://{address}\r\n\r\n"),
+    );
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(
+      !response.contains("Access-Control-Allow-Origin"),
+      "{response}"
+    );
+
+    let mut state = State {
+      db: -12.0,
+      ..default()
+    };
+    server.update(&Status::new(&state, Some(60.0)));
+
+    let response = request(
+      address,
+      &format!("GET /state HTTP/1.1\r\nHost: {address}\r\n\r\n"),
+    );
+    assert!(response.contains(r#""db":-12.0"#), "{response}");
+
+    let (mut websocket, _response) = tungstenite::connect(format!("ws://{address}/state")).unwrap();
+    assert!(
+      websocket
+        .read()
+        .unwrap()
+        .to_text()
+        .unwrap()
+        .contains(r#""db":-12.0"#),
+    );
+
+    state.db = 3.0;
+    server.update(&Status::new(&state, Some(60.0)));
+
+    assert!(
+      websocket
+        .read()
+        .unwrap()
+        .to_text()
+        .unwrap()
+        .contains(r#""db":3.0"#),
+    );
+  }
+}
@@ -0,0 +1,206 @@
+use super::*;
+
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(Error)), visibility(pub(crate)))]
+pub(crate) enum SessionParseError {
+  #[snafu(display("line {line}: app command `{name}` cannot be replayed"))]
+  AppCommand { line: usize, name: String },
+  #[snafu(display("line {line}: unknown command `{name}`"))]
+  Command { line: usize, name: String },
+  #[snafu(display("line {line}: expected {expected}"))]
+  Expected { line: usize, expected: &'static str },
+  #[snafu(display("line {line}: invalid seed `{text}`"))]
+  Seed { line: usize, text: String },
+  #[snafu(display("line {line}: invalid time `{text}`"))]
+  Time { line: usize, text: String },
+}
+
+pub(crate) struct Session {
+  pub(crate) invocations: Vec<Invocation>,
+  pub(crate) seed: u64,
+}
+
+impl Session {
+  pub(crate) fn load(path: &Utf8Path) -> Result<Self> {
+    let src = fs::read_to_string(path).context(error::FilesystemIo { path })?;
+    Self::parse(&src).context(error::SessionParse { path })
+  }
+
+  pub(crate) fn parse(src: &str) -> Result<Self, SessionParseError> {
+    let commands = Commands::new();
+
+    let mut seed = None;
+
+    let mut invocations = Vec::new();
+
+    for (i, text) in src.lines().enumerate() {
+      let line = i + 1;
+
+      let mut tokens = text
+        .split_whitespace()
+        .take_while(|token| !token.starts_with('#'))
+        .peekable();
+
+      let Some(token) = tokens.next() else {
+        continue;
+      };
+
+      if seed.is_none() {
+        ensure!(
+          token == "seed",
+          ExpectedError {
+            line,
+            expected: "seed",
+          }
+        );
+
+        let token = tokens.next().context(ExpectedError {
+          line,
+          expected: "seed",
+        })?;
+
+        seed = Some(
+          token
+            .parse::<u64>()
+            .ok()
+            .context(SeedError { line, text: token })?,
+        );
+
+        continue;
+      }
+
+      let time = token
+        .parse::<f64>()
+        .ok()
+        .filter(|time| time.is_finite())
+        .context(TimeError { line, text: token })?;
+
+      let position = tokens.peek().copied().and_then(Position::parse);
+
+      if position.is_some() {
+        tokens.next();
+      }
+
+      let name = tokens.next().context(ExpectedError {
+        line,
+        expected: "command",
+      })?;
+
+      let entry = commands.name(name).context(CommandError { line, name })?;
+
+      ensure!(
+        !matches!(
+          entry.command,
+          Command::App(_) | Command::AppEventLoop(_) | Command::AppFallible(_),
+        ),
+        AppCommandError { line, name },
+      );
+
+      invocations.push(Invocation {
+        arguments: tokens.map(str::to_owned).collect(),
+        entry,
+        position,
+        time,
+      });
+    }
+
+    Ok(Self {
+      invocations,
+      seed: seed.context(ExpectedError {
+        line: src.lines().count() + 1,
+        expected: "seed",
+      })?,
+    })
+  }
+
+  pub(crate) fn tap(&self) -> Vec<Invocation> {
+    self
+      .invocations
+      .iter()
+      .filter(|invocation| invocation.entry.command.is_tap())
+      .cloned()
+      .collect()
+  }
+}
+
+impl Display for Session {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    writeln!(f, "seed {}", self.seed)?;
+
+    for invocation in &self.invocations {
+      writeln!(f, "{invocation}")?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, generated::*, position::bbq};
+
+  #[test]
+  fn comments() {
+    let session =
+      Session::parse("# session\nseed 1 # seed\n0.5 set velocity/x 0.5 # encoder\n1.0 scene a#b\n")
+        .unwrap();
+
+    assert_eq!(session.seed, 1);
+    assert_eq!(session.invocations.len(), 2);
+    assert_eq!(session.invocations[0].arguments, ["velocity/x", "0.5"]);
+    assert_eq!(session.invocations[1].arguments, ["a#b"]);
+  }
+
+  #[test]
+  fn errors() {
+    #[track_caller]
+    fn case(src: &str, expected: &str) {
+      assert_eq!(Session::parse(src).err().unwrap().to_string(), expected);
+    }
+
+    case("", "line 1: expected seed");
+    case("0.0 blaster", "line 1: expected seed");
+    case("seed x", "line 1: invalid seed `x`");
+    case("seed 1\nx blaster", "line 2: invalid time `x`");
+    case("seed 1\n0.5", "line 2: expected command");
+    case("seed 1\n0.5 1.1.1", "line 2: expected command");
+    case("seed 1\n0.5 foo", "line 2: unknown command `foo`");
+    case(
+      "seed 1\n0.5 capture",
+      "line 2: app command `capture` cannot be replayed",
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    let session = Session {
+      invocations: vec![
+        Invocation {
+          arguments: Vec::new(),
+          entry: BLASTER,
+          position: None,
+          time: 0.1,
+        },
+        Invocation {
+          arguments: vec!["-12".into()],
+          entry: DB,
+          position: Some(bbq(3, 2, 1)),
+          time: 10.016_666_666_666_666,
+        },
+      ],
+      seed: 42,
+    };
+
+    let text = session.to_string();
+
+    assert_eq!(
+      text,
+      "seed 42\n0.1 blaster\n10.016666666666666 3.2.1 db -12\n"
+    );
+
+    let parsed = Session::parse(&format!("# comment\n{text}# comment\n")).unwrap();
+
+    assert_eq!(parsed.seed, 42);
+    assert_eq!(parsed.to_string(), text);
+  }
+}
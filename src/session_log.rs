@@ -0,0 +1,62 @@
+use {super::*, std::io::LineWriter};
+
+pub(crate) struct SessionLog {
+  path: Utf8PathBuf,
+  writer: LineWriter<File>,
+}
+
+impl SessionLog {
+  pub(crate) fn append(&mut self, invocation: &Invocation) -> Result {
+    writeln!(self.writer, "{invocation}").context(error::FilesystemIo { path: &self.path })
+  }
+
+  pub(crate) fn new(path: Utf8PathBuf, seed: u64) -> Result<Self> {
+    let file = File::create(&path).context(error::FilesystemIo { path: &path })?;
+
+    let mut writer = LineWriter::new(file);
+
+    writeln!(writer, "seed {seed}").context(error::FilesystemIo { path: &path })?;
+
+    Ok(Self { path, writer })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, generated::*};
+
+  #[test]
+  fn append() {
+    let (_tempdir, path) = tempdir().unwrap();
+
+    let path = path.join("session.txt");
+
+    let mut log = SessionLog::new(path.clone(), 7).unwrap();
+
+    log
+      .append(&Invocation {
+        arguments: Vec::new(),
+        entry: BLASTER,
+        position: None,
+        time: 0.5,
+      })
+      .unwrap();
+
+    log
+      .append(&Invocation {
+        arguments: vec!["2.1.1".into()],
+        entry: SEEK,
+        position: None,
+        time: 1.0,
+      })
+      .unwrap();
+
+    let session = Session::load(&path).unwrap();
+
+    assert_eq!(session.seed, 7);
+    assert_eq!(session.invocations.len(), 2);
+    assert_eq!(session.invocations[0].entry.name, "blaster");
+    assert_eq!(session.invocations[1].entry.name, "seek");
+    assert_eq!(session.tap().len(), 1);
+  }
+}
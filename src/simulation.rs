@@ -0,0 +1,317 @@
+use {
+  super::*,
+  std::{iter::Peekable, vec},
+};
+
+pub(crate) struct Simulation {
+  pub(crate) analyzer: Analyzer,
+  elapsed: f64,
+  history: History,
+  invocations: Peekable<vec::IntoIter<Invocation>>,
+  onsets: BTreeMap<Band, CommandEntry>,
+  rng: SmallRng,
+  script: Option<Script>,
+  pub(crate) state: State,
+}
+
+impl Simulation {
+  pub(crate) fn new(options: &Options, config: &Config, session: Option<Session>) -> Result<Self> {
+    let mut rng = session.as_ref().map_or_else(
+      || options.rng(),
+      |session| SmallRng::seed_from_u64(session.seed),
+    );
+
+    let state = options.state(config, &mut rng)?;
+
+    let script = if session.is_some() {
+      None
+    } else {
+      options.script()?
+    };
+
+    if let Some(script) = &script {
+      for CommandEntry { name, command } in script.commands() {
+        match command {
+          Command::App(_)
+          | Command::AppEventLoop(_)
+          | Command::AppFallible(_)
+          | Command::Tap(_)
+          | Command::TapArguments(_) => {
+            return Err(error::HeadlessScriptAppCommand { command: name }.build());
+          }
+          Command::History(_)
+          | Command::HistoryState(_)
+          | Command::RngState(_)
+          | Command::State(_) => {}
+          Command::RngStateArguments(_) | Command::StateArguments(_) => {
+            return Err(error::HeadlessScriptArguments { command: name }.build());
+          }
+        }
+      }
+    }
+
+    let mut onsets = BTreeMap::new();
+
+    if session.is_none() {
+      let bindings = Bindings::new(config.bindings())?;
+
+      for band in Band::iter() {
+        let Some(entry) = bindings.onset(band) else {
+          continue;
+        };
+
+        match entry.command {
+          Command::History(_)
+          | Command::HistoryState(_)
+          | Command::RngState(_)
+          | Command::State(_) => {
+            onsets.insert(band, entry);
+          }
+          _ => log::warn!(
+            "ignoring {} onset binding: command {} cannot be used headless",
+            band.name(),
+            entry.name,
+          ),
+        }
+      }
+    }
+
+    let mut invocations = session
+      .map(|session| session.invocations)
+      .unwrap_or_default();
+
+    invocations.retain(|invocation| !invocation.entry.command.is_tap());
+
+    Ok(Self {
+      analyzer: Analyzer::new(),
+      elapsed: 0.0,
+      history: History::default(),
+      invocations: invocations.into_iter().peekable(),
+      onsets,
+      rng,
+      script,
+      state,
+    })
+  }
+
+  pub(crate) fn preroll(&mut self, tap: &mut Tap, fps: Fps, time: f64) -> Result {
+    let mut samples = vec![0.0; fps.spf(tap.format())?];
+
+    while tap.time() < time {
+      let done = tap.is_done();
+
+      tap.write(&mut samples);
+
+      let sound = tap.drain();
+
+      let tick = tap.tick(fps.dt());
+
+      self.update(&sound, done, tick)?;
+
+      self.tick(tick)?;
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn tick(&mut self, tick: Tick) -> Result {
+    self.history.tick(&mut self.state);
+
+    if let Some(script) = &self.script {
+      for &entry in script.tick(tick) {
+        log::info!("dispatching script command {}", entry.name);
+        self
+          .history
+          .dispatch(
+            &mut self.rng,
+            &mut self.state,
+            Invocation {
+              arguments: Vec::new(),
+              entry,
+              position: tick.position,
+              time: tick.time,
+            },
+          )
+          .context(error::CommandArguments {
+            command: entry.name,
+          })?;
+      }
+    }
+
+    self.elapsed += tick.dt.as_secs_f64();
+
+    while let Some(invocation) = self
+      .invocations
+      .next_if(|invocation| invocation.time < self.elapsed)
+    {
+      let command = invocation.entry.name;
+      log::info!("replaying command {command}");
+      self
+        .history
+        .dispatch(&mut self.rng, &mut self.state, invocation)
+        .context(error::CommandArguments { command })?;
+    }
+
+    self.state.tick(tick);
+
+    Ok(())
+  }
+
+  pub(crate) fn update(&mut self, sound: &Sound, done: bool, tick: Tick) -> Result {
+    self.analyzer.update(sound, done, &self.state);
+
+    for band in self.analyzer.onsets() {
+      let Some(&entry) = self.onsets.get(band) else {
+        continue;
+      };
+
+      log::info!("{} onset: {}", band.name(), entry.name);
+
+      self
+        .history
+        .dispatch(
+          &mut self.rng,
+          &mut self.state,
+          Invocation {
+            arguments: Vec::new(),
+            entry,
+            position: tick.position,
+            time: tick.time,
+          },
+        )
+        .context(error::CommandArguments {
+          command: entry.name,
+        })?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    assert_float_eq::assert_float_absolute_eq,
+    fundsp::prelude::{pan, zero},
+  };
+
+  #[test]
+  fn onsets() {
+    let format = SoundFormat {
+      channels: 1,
+      sample_rate: DEFAULT_SAMPLE_RATE,
+    };
+
+    let silence = Sound::new(format, vec![0.0; 800]);
+
+    let kick = Sound::new(
+      format,
+      (0..800)
+        .map(|i| (i as f32 / 48_000.0 * 100.0 * TAU).sin())
+        .collect(),
+    );
+
+    let config = serde_yaml::from_str::<Config>(
+      "
+bindings:
+  onsets:
+  - {band: kick, command: increment-db}
+",
+    )
+    .unwrap();
+
+    let tick = Tick {
+      dt: Duration::ZERO,
+      last: None,
+      position: None,
+      tempo: None,
+      time: 0.0,
+    };
+
+    let mut simulation = Simulation::new(&Options::default(), &config, None).unwrap();
+
+    let db = simulation.state.db;
+
+    simulation.update(&silence, false, tick).unwrap();
+    assert_float_absolute_eq!(simulation.state.db, db);
+
+    simulation.update(&kick, false, tick).unwrap();
+    assert_float_absolute_eq!(simulation.state.db, db + 1.0);
+  }
+
+  #[test]
+  fn preroll() {
+    let (_tempdir, path) = tempdir().unwrap();
+
+    let script = path.join("script.txt");
+
+    fs::write(&script, "1.2.1 increment-db\n").unwrap();
+
+    let options = Options {
+      script: Some(script),
+      seed: Some(0),
+      ..default()
+    };
+
+    let mut tap = Tap::new(&options, DEFAULT_SAMPLE_RATE);
+
+    tap.sequence_track(
+      &Track {
+        tempo: Tempo {
+          bpm: 120.0,
+          offset: 0.0,
+        },
+        wave: Arc::new(Wave::render(
+          DEFAULT_SAMPLE_RATE.into(),
+          2.0,
+          &mut (zero() >> pan(0.0)),
+        )),
+      },
+      0.0,
+      0.0,
+    );
+
+    let mut simulation = Simulation::new(&options, &Config::default(), None).unwrap();
+
+    let db = simulation.state.db;
+
+    let fps = Fps::from(DEFAULT_FPS);
+
+    simulation.preroll(&mut tap, fps, 0.25).unwrap();
+
+    assert_float_absolute_eq!(simulation.state.db, db);
+
+    simulation.preroll(&mut tap, fps, 1.0).unwrap();
+
+    assert_float_absolute_eq!(simulation.state.db, db + 1.0);
+  }
+
+  #[test]
+  fn replay() {
+    let session = Session::parse("seed 0\n0.5 increment-db\n0.6 seek 1.1.1\n").unwrap();
+
+    let mut simulation =
+      Simulation::new(&Options::default(), &Config::default(), Some(session)).unwrap();
+
+    let db = simulation.state.db;
+
+    let tick = Tick {
+      dt: Duration::from_secs_f64(0.25),
+      last: None,
+      position: None,
+      tempo: None,
+      time: 0.0,
+    };
+
+    simulation.tick(tick).unwrap();
+    simulation.tick(tick).unwrap();
+    assert_float_absolute_eq!(simulation.state.db, db);
+
+    simulation.tick(tick).unwrap();
+    assert_float_absolute_eq!(simulation.state.db, db + 1.0);
+
+    simulation.tick(tick).unwrap();
+    assert_float_absolute_eq!(simulation.state.db, db + 1.0);
+  }
+}
@@ -20,6 +20,16 @@ impl Sound {
     Ok(())
   }
 
+  pub(crate) fn channel(&self, channel: usize) -> impl Iterator<Item = f32> {
+    let channels = self.format.channels.into_usize();
+    self
+      .samples
+      .iter()
+      .skip(channel.min(channels - 1))
+      .step_by(channels)
+      .copied()
+  }
+
   pub(crate) fn downmix(&self) -> impl Iterator<Item = f32> {
     self
       .samples
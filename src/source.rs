@@ -0,0 +1,10 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Source {
+  Band(Band),
+  #[default]
+  Rms,
+  Width,
+}
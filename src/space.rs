@@ -38,7 +38,8 @@ const YIQ_INVERSE: Mat3f = matrix!(
   1.0, -1.106,  1.703;
 );
 
-#[derive(Clone, Copy, Debug, PartialEq, EnumIter)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, EnumIter, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub(crate) enum Space {
   Blaster,
   CenteredRgb,
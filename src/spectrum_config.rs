@@ -0,0 +1,32 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct SpectrumConfig {
+  pub(crate) bins: usize,
+  pub(crate) db: bool,
+  pub(crate) overlap: f32,
+  pub(crate) scale: FrequencyScale,
+  pub(crate) size: usize,
+  pub(crate) window: WindowFunction,
+}
+
+impl Default for SpectrumConfig {
+  fn default() -> Self {
+    Self {
+      bins: 256,
+      db: false,
+      overlap: 0.75,
+      scale: FrequencyScale::default(),
+      size: 2048,
+      window: WindowFunction::default(),
+    }
+  }
+}
+
+impl SpectrumConfig {
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  pub(crate) fn hop(&self) -> usize {
+    ((self.size as f32 * (1.0 - self.overlap.clamp(0.0, 1.0))) as usize).max(1)
+  }
+}
@@ -1,9 +1,12 @@
 use super::*;
 
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
 pub(crate) struct State {
   pub(crate) alpha: f32,
   pub(crate) bandpass: Vec2f,
+  pub(crate) bands: BTreeMap<Band, BandConfig>,
+  #[serde(skip)]
   pub(crate) callback: Option<Box<dyn Callback>>,
   pub(crate) capture_status: bool,
   pub(crate) complexity: f32,
@@ -13,7 +16,9 @@ pub(crate) struct State {
   pub(crate) filter: Filter,
   pub(crate) filters: Vec<Filter>,
   pub(crate) interpolate: bool,
+  #[serde(skip)]
   pub(crate) position: Option<Position>,
+  pub(crate) spectrum: SpectrumConfig,
   pub(crate) spread: bool,
   pub(crate) status: bool,
   pub(crate) tile: bool,
@@ -28,6 +33,7 @@ impl Default for State {
     Self {
       alpha: 0.5,
       bandpass: Vec2f::new(20.0, 400.0),
+      bands: Band::defaults(),
       position: None,
       callback: None,
       capture_status: false,
@@ -39,6 +45,7 @@ impl Default for State {
       filters: Vec::new(),
       viewport: Viewport::default(),
       interpolate: false,
+      spectrum: SpectrumConfig::default(),
       spread: false,
       status: false,
       tile: false,
@@ -60,6 +67,11 @@ impl State {
     self
   }
 
+  pub(crate) fn band(&mut self, band: Band) -> &mut Self {
+    self.filter.source = Source::Band(band);
+    self
+  }
+
   #[allow(unused)]
   pub(crate) fn base(&mut self, base: f32) -> &mut Self {
     self.filter.base = base;
@@ -143,6 +155,11 @@ impl State {
     self
   }
 
+  pub(crate) fn load(path: &Utf8Path) -> Result<Self> {
+    let yaml = fs::read_to_string(path).context(error::FilesystemIo { path })?;
+    serde_yaml::from_str(&yaml).context(error::SceneDeserialize { path })
+  }
+
   #[cfg(test)]
   pub(crate) fn mirror_x(&mut self, mirror: Mirror) -> &mut Self {
     self.filter.mirror.x = mirror;
@@ -217,6 +234,11 @@ impl State {
     self
   }
 
+  pub(crate) fn save(&self, path: &Utf8Path) -> Result {
+    let yaml = serde_yaml::to_string(self).context(error::SceneSerialize)?;
+    fs::write(path, yaml).context(error::FilesystemIo { path })
+  }
+
   pub(crate) fn scale(&mut self, n: f32) -> &mut Self {
     self.filter.position *= Mat3f::new_scaling(n);
     self
@@ -227,6 +249,11 @@ impl State {
     self
   }
 
+  pub(crate) fn source(&mut self, source: Source) -> &mut Self {
+    self.filter.source = source;
+    self
+  }
+
   pub(crate) fn spread(&mut self, spread: bool) -> &mut Self {
     self.spread = spread;
     self
@@ -327,6 +354,11 @@ impl State {
     self
   }
 
+  pub(crate) fn width(&mut self) -> &mut Self {
+    self.filter.source = Source::Width;
+    self
+  }
+
   pub(crate) fn wrap(&mut self, wrap: bool) -> &mut Self {
     self.filter.wrap = wrap;
     self
@@ -337,3 +369,41 @@ impl State {
     self
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn missing_fields_are_defaulted() {
+    let state = serde_yaml::from_str::<State>(
+      "
+db: -12.0
+filters:
+- field: !circle
+    radius: 0.25
+  mirror: [triangle, off]
+",
+    )
+    .unwrap();
+
+    assert_eq!(state.db, -12.0);
+    assert_eq!(state.alpha, State::default().alpha);
+    assert_eq!(state.filters.len(), 1);
+    assert_eq!(state.filters[0].field.parameter(), 0.25);
+    assert_eq!(state.filters[0].mirror.x, Mirror::Triangle);
+    assert_eq!(state.filters[0].mirror.y, Mirror::Off);
+  }
+
+  #[test]
+  fn round_trip() {
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for scene in Scene::iter() {
+      let state = scene.state(&mut rng);
+      let yaml = serde_yaml::to_string(&state).unwrap();
+      let round_trip = serde_yaml::from_str::<State>(&yaml).unwrap();
+      assert_eq!(serde_yaml::to_string(&round_trip).unwrap(), yaml, "{scene}");
+    }
+  }
+}
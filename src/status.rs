@@ -0,0 +1,41 @@
+use super::*;
+
+#[derive(Serialize)]
+pub(crate) struct Status {
+  alpha: f32,
+  db: f32,
+  encoder: f32,
+  filters: Vec<FilterStatus>,
+  fps: Option<f32>,
+  position: Option<String>,
+  velocity: [f32; 4],
+}
+
+#[derive(Serialize)]
+struct FilterStatus {
+  field: Field,
+  icon: char,
+  preset: Option<Preset>,
+}
+
+impl Status {
+  pub(crate) fn new(state: &State, fps: Option<f32>) -> Self {
+    Self {
+      alpha: state.alpha,
+      db: state.db,
+      encoder: state.encoder,
+      filters: state
+        .filters
+        .iter()
+        .map(|filter| FilterStatus {
+          field: filter.field,
+          icon: filter.icon(),
+          preset: filter.preset,
+        })
+        .collect(),
+      fps,
+      position: state.position.map(|position| position.to_string()),
+      velocity: state.velocity.into(),
+    }
+  }
+}
@@ -4,6 +4,7 @@ mod bindings;
 mod capture;
 mod commands;
 mod probe;
+mod render;
 mod run;
 mod shader;
 
@@ -13,6 +14,7 @@ pub(crate) enum Subcommand {
   Capture(capture::Capture),
   Commands,
   Probe,
+  Render(render::Render),
   Run(run::Run),
   Shader,
 }
@@ -20,10 +22,11 @@ pub(crate) enum Subcommand {
 impl Subcommand {
   pub(crate) fn run(self, options: Options, config: Config) -> Result {
     match self {
-      Self::Bindings => bindings::run(),
+      Self::Bindings => bindings::run(&config),
       Self::Capture(capture) => capture.run(options, config),
       Self::Commands => commands::run(),
       Self::Probe => probe::run(),
+      Self::Render(render) => render.run(options, config),
       Self::Run(run) => run.run(options, config),
       Self::Shader => shader::run(),
     }
@@ -1,6 +1,6 @@
 use super::*;
 
-pub(crate) fn run() -> Result {
-  println!("{}", Bindings::new());
+pub(crate) fn run(config: &Config) -> Result {
+  println!("{}", Bindings::new(config.bindings())?);
   Ok(())
 }
@@ -1,7 +1,5 @@
 use super::*;
 
-const DEFAULT_SAMPLE_RATE: u32 = 48_000;
-
 const PROGRESS_CHARS: &str = "█▉▊▋▌▍▎▏ ";
 
 const TICK_CHARS: &str = concat!(
@@ -25,9 +23,17 @@ const TICK_CHARS: &str = concat!(
 
 #[derive(Parser)]
 pub(crate) struct Capture {
-  #[arg(long)]
+  #[arg(conflicts_with = "end", long)]
   duration: Option<NonZeroU32>,
   #[arg(long)]
+  end: Option<Timestamp>,
+  #[arg(default_value_t, long, value_enum)]
+  format: VideoFormat,
+  #[arg(long)]
+  replay: Option<Utf8PathBuf>,
+  #[arg(long)]
+  start: Option<Timestamp>,
+  #[arg(long)]
   stem: Option<String>,
 }
 
@@ -44,56 +50,35 @@ impl Capture {
 
     options.add_source(&config, &mut tap)?;
 
-    let mut analyzer = Analyzer::new();
-
-    let mut rng = options.rng();
-
-    let mut state = options.state(&config, &mut rng)?;
-
-    let script = options.script();
-
-    if let Some(script) = &script {
-      for CommandEntry { name, command } in script.commands() {
-        match command {
-          Command::App(_) | Command::AppEventLoop(_) | Command::AppFallible(_) => {
-            return Err(error::CaptureScriptAppCommand { command: name }.build());
-          }
-          Command::History(_)
-          | Command::HistoryState(_)
-          | Command::RngState(_)
-          | Command::State(_) => {}
-        }
-      }
-    }
-
-    let mut history = History::default();
-
-    let resolution = options.resolution.unwrap_or(DEFAULT_RESOLUTION);
+    let session = self.replay.as_deref().map(Session::load).transpose()?;
 
-    let size = Size::new(
-      options.width.unwrap_or(resolution),
-      options.height.unwrap_or(resolution),
-    );
+    let invocations = session.as_ref().map(Session::tap).unwrap_or_default();
 
-    let (size, resolution) = options.size(size);
+    let mut simulation = Simulation::new(&options, &config, session)?;
 
-    let mut renderer = pollster::block_on(Renderer::new(
-      options.image_format,
-      None,
-      resolution,
-      size,
-      None,
-    ))?;
+    let mut renderer = options.renderer()?;
 
     let fps = options.fps.unwrap_or(DEFAULT_FPS.into());
 
     let spf = fps.spf(tap.format())?;
 
-    let (tx, rx) = mpsc::channel();
+    let start = self
+      .start
+      .map(|start| start.seconds(tap.tempo()))
+      .transpose()?
+      .unwrap_or_default();
 
+    let end = self.end.map(|end| end.seconds(tap.tempo())).transpose()?;
+
+    if let Some(end) = end {
+      ensure!(end > start, error::CaptureRange { end, start });
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     let frames = self
       .duration
-      .map(|duration| u64::from(duration.get()) * u64::from(fps.fps().get()));
+      .map(|duration| u64::from(duration.get()) * u64::from(fps.fps().get()))
+      .or_else(|| end.map(|end| ((end - start) * f64::from(fps.fps().get())).ceil() as u64));
 
     let progress = if options.verbose {
       ProgressBar::hidden()
@@ -104,65 +89,62 @@ impl Capture {
       ProgressBar::new_spinner().with_style(ProgressStyle::default_spinner().tick_chars(TICK_CHARS))
     };
 
-    let mut samples = vec![0.0; spf];
+    let recorder = RecorderThread::new(Recorder::new(
+      fps,
+      self.format,
+      &options,
+      false,
+      renderer.size(),
+      sound_format,
+    )?)?;
 
-    let mut recorder = Recorder::new(fps, &options, false, renderer.size(), sound_format)?;
+    let audio = AudioThread::new(tap, fps.dt(), spf, invocations)?;
+
+    let mut readback = Readback::new(Readback::DEPTH);
 
     let mut done = false;
-    for frame in 0.. {
+    let mut frame = 0;
+    loop {
       if frames.map_or(done, |frames| frame == frames) || interrupt.interrupted() {
         break;
       }
 
-      progress.inc(1);
-
-      done = tap.is_done();
-
-      tap.write(&mut samples);
+      if recorder.is_finished() {
+        break;
+      }
 
-      let sound = tap.drain();
-      analyzer.update(&sound, done, &state);
-      renderer.render(&analyzer, &state, None)?;
+      let Some((time, sound, finished, tick)) = audio.recv() else {
+        break;
+      };
 
-      let tx = tx.clone();
-      renderer.capture(move |image| {
-        if let Err(err) = tx.send(image) {
-          eprintln!("failed to send captured frame: {err}");
-        }
-      })?;
+      let preroll = time < start;
 
-      renderer.poll()?;
+      done = finished;
 
-      let image = rx.recv().unwrap();
+      simulation.update(&sound, done, tick)?;
 
-      recorder.frame(frame, image, sound)?;
+      if !preroll {
+        progress.inc(1);
 
-      history.tick(&mut state);
+        renderer.render(&simulation.analyzer, &simulation.state, None)?;
 
-      let tick = tap.tick(fps.dt());
+        let tx = recorder.tx().clone();
+        readback.capture(&renderer, move |image| {
+          tx.send((frame, image, sound)).ok();
+        })?;
 
-      if let Some(script) = &script {
-        for CommandEntry { name, command } in script.tick(tick) {
-          log::info!("dispatching script command {name}");
-          match command {
-            Command::App(_) | Command::AppEventLoop(_) | Command::AppFallible(_) => unreachable!(),
-            Command::History(command) => command(&mut history),
-            Command::HistoryState(command) => command(&mut history, &mut state),
-            Command::RngState(command) => {
-              history.states.push(state.clone());
-              command(&mut rng, &mut state);
-            }
-            Command::State(command) => {
-              history.states.push(state.clone());
-              command(&mut state);
-            }
-          }
-        }
+        frame += 1;
       }
 
-      state.tick(tick);
+      simulation.tick(tick)?;
     }
 
+    readback.finish(&renderer)?;
+
+    audio.finish()?;
+
+    renderer.finish()?;
+
     progress.finish();
 
     recorder.finish(&options, &config, self.stem.as_deref())?;
@@ -0,0 +1,40 @@
+use super::*;
+
+#[derive(Parser)]
+pub(crate) struct Render {
+  output: Utf8PathBuf,
+  #[arg(default_value_t = 0.0, long)]
+  time: f64,
+}
+
+impl Render {
+  pub(crate) fn run(self, options: Options, config: Config) -> Result {
+    let mut tap = Tap::new(&options, DEFAULT_SAMPLE_RATE);
+
+    options.add_source(&config, &mut tap)?;
+
+    let mut simulation = Simulation::new(&options, &config, None)?;
+
+    let mut renderer = options.renderer()?;
+
+    let fps = options.fps.unwrap_or(DEFAULT_FPS.into());
+
+    simulation.preroll(&mut tap, fps, self.time)?;
+
+    renderer.render(&simulation.analyzer, &simulation.state, None)?;
+
+    let (tx, rx) = mpsc::channel();
+
+    renderer.capture(move |image| {
+      if let Err(err) = tx.send(image) {
+        eprintln!("failed to send captured frame: {err}");
+      }
+    })?;
+
+    renderer.poll()?;
+
+    rx.recv().unwrap().save(&self.output)?;
+
+    Ok(())
+  }
+}
@@ -4,6 +4,14 @@ use super::*;
 pub(crate) struct Run {
   #[arg(long)]
   fullscreen: bool,
+  #[arg(long, requires = "listen")]
+  hostname: Option<String>,
+  #[arg(long)]
+  listen: Option<SocketAddr>,
+  #[arg(long)]
+  log: Option<Utf8PathBuf>,
+  #[arg(long)]
+  osc_port: Option<u16>,
   #[arg(long)]
   present_mode: Option<PresentMode>,
   #[arg(long)]
@@ -16,7 +24,17 @@ impl Run {
       .record
       .then(|| options.fps.unwrap_or(DEFAULT_FPS.into()));
 
-    let mut app = App::new(config, self.fullscreen, options, self.present_mode, record)?;
+    let mut app = App::new(
+      config,
+      self.fullscreen,
+      self.hostname,
+      self.listen,
+      self.log,
+      options,
+      self.osc_port,
+      self.present_mode,
+      record,
+    )?;
 
     let event_loop = EventLoop::with_user_event()
       .build()
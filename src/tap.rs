@@ -8,7 +8,7 @@ use {
     combinator::An,
     prelude::U0,
     realseq::SequencerBackend,
-    sequencer::{Fade, ReplayMode, Sequencer},
+    sequencer::{EventId, Fade, ReplayMode, Sequencer},
     wave::{Wave, WavePlayer},
   },
   rubato::{Fft, FixedSync},
@@ -16,84 +16,65 @@ use {
 
 pub(crate) struct Tap {
   backend: Arc<Mutex<Backend>>,
+  clock: Option<Clock>,
+  crossfade: f64,
   done: f64,
   format: SoundFormat,
   last: Option<Position>,
   muted: Arc<AtomicBool>,
   paused: Arc<AtomicBool>,
+  playback: Option<Playback>,
+  playlist: Vec<Utf8PathBuf>,
+  preloads: Vec<Preload>,
   sequencer: Sequencer,
   stream: Option<Stream>,
+  taps: Vec<f64>,
   tempo: Option<Tempo>,
   time: f64,
+  track: Option<usize>,
 }
 
 impl Tap {
   pub(crate) const CHANNELS: u16 = 2;
+  const SEEK_FADE: f64 = 0.01;
+  const TAP_TIMEOUT: f64 = 2.0;
 
-  pub(crate) fn drain(&mut self) -> Sound {
-    self.drain_exact(None).unwrap()
-  }
-
-  pub(crate) fn drain_exact(&mut self, count: Option<usize>) -> Option<Sound> {
-    let mut backend = self.backend.lock().unwrap();
-
-    let count = count.unwrap_or(backend.samples.len());
-
-    if backend.samples.len() < count {
-      return None;
-    }
-
-    let sound = Sound::new(self.format, backend.samples.drain(..count).collect());
-
-    self.time += sound.duration().as_secs_f64();
-
-    Some(sound)
-  }
-
-  pub(crate) fn format(&self) -> SoundFormat {
-    self.format
-  }
-
-  pub(crate) fn is_done(&self) -> bool {
-    self.time >= self.done
-  }
-
-  pub(crate) fn load_track(&self, path: &Utf8Path) -> Result<Track> {
-    Ok(Track {
-      tempo: Tempo::detect(path)?,
-      wave: self.load_wave(path)?,
-    })
-  }
-
-  pub(crate) fn load_wave(&self, path: &Utf8Path) -> Result<Arc<Wave>> {
-    let mut input = Wave::load(path).context(error::WaveLoad)?;
+  pub(crate) fn decode(path: &Utf8Path, sample_rate: u32) -> Result<Arc<Wave>> {
+    let mut input = AudioFormat::from_path(path)
+      .context(error::AudioFormat { path })?
+      .decode(path)?;
 
     if input.is_empty() {
-      return Ok(Arc::new(Wave::new(1, self.format.sample_rate as f64)));
+      return Ok(Arc::new(Wave::new(1, sample_rate.into())));
     }
 
     while input.channels() > Self::CHANNELS.into_usize() {
       input.remove_channel(input.channels() - 1);
     }
 
-    let sample_rate = input.sample_rate();
+    let input_sample_rate = input.sample_rate();
 
-    if sample_rate.fract() != 0.0 {
-      return Err(error::WaveSampleRate { sample_rate }.build());
+    if input_sample_rate.fract() != 0.0 {
+      return Err(
+        error::WaveSampleRate {
+          sample_rate: input_sample_rate,
+        }
+        .build(),
+      );
     }
 
     #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-    let sample_rate = sample_rate as usize;
+    let input_sample_rate = input_sample_rate as usize;
 
-    if sample_rate == self.format.sample_rate.into_usize() {
+    if input_sample_rate == sample_rate.into_usize() {
       return Ok(Arc::new(input));
     }
 
     let start = Instant::now();
 
     let mut resampler = Fft::<f32>::new(
-      sample_rate,
-      self.format.sample_rate.into_usize(),
+      input_sample_rate,
+      sample_rate.into_usize(),
       1024,
       2,
       input.channels(),
@@ -103,7 +84,7 @@ impl Tap {
 
     let needed = resampler.process_all_needed_output_len(input.len());
 
-    let mut output = Wave::new(input.channels(), self.format.sample_rate as f64);
+    let mut output = Wave::new(input.channels(), sample_rate.into());
     output.resize(needed);
 
     let input = WaveAdapter(input);
@@ -122,6 +103,76 @@ impl Tap {
     Ok(Arc::new(output))
   }
 
+  pub(crate) fn dispatch(&mut self, invocation: &Invocation) -> Result<(), ArgumentError> {
+    match invocation.entry.command {
+      Command::Tap(command) => command(self),
+      Command::TapArguments(command) => command(self, &invocation.arguments)?,
+      Command::App(_)
+      | Command::AppEventLoop(_)
+      | Command::AppFallible(_)
+      | Command::History(_)
+      | Command::HistoryState(_)
+      | Command::RngState(_)
+      | Command::RngStateArguments(_)
+      | Command::State(_)
+      | Command::StateArguments(_) => {
+        unreachable!(
+          "non-tap command {} dispatched to tap",
+          invocation.entry.name
+        )
+      }
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn drain(&mut self) -> Sound {
+    self.drain_exact(None).unwrap()
+  }
+
+  pub(crate) fn drain_exact(&mut self, count: Option<usize>) -> Option<Sound> {
+    let mut backend = self.backend.lock().unwrap();
+
+    let count = count.unwrap_or(backend.samples.len());
+
+    if backend.samples.len() < count {
+      return None;
+    }
+
+    let sound = Sound::new(self.format, backend.samples.drain(..count).collect());
+
+    drop(backend);
+
+    self.elapse(sound.duration());
+
+    Some(sound)
+  }
+
+  pub(crate) fn elapse(&mut self, duration: Duration) {
+    self.time += duration.as_secs_f64();
+  }
+
+  pub(crate) fn format(&self) -> SoundFormat {
+    self.format
+  }
+
+  pub(crate) fn is_done(&self) -> bool {
+    self.time >= self.done
+  }
+
+  pub(crate) fn load_track(&self, path: &Utf8Path) -> Result<Track> {
+    let wave = self.load_wave(path)?;
+
+    Ok(Track {
+      tempo: Tempo::load(path, &wave)?,
+      wave,
+    })
+  }
+
+  pub(crate) fn load_wave(&self, path: &Utf8Path) -> Result<Arc<Wave>> {
+    Self::decode(path, self.format.sample_rate)
+  }
+
   pub(crate) fn new(options: &Options, sample_rate: u32) -> Self {
     let mut sequencer = Sequencer::new(0, Self::CHANNELS.into(), ReplayMode::None);
     sequencer.set_sample_rate(sample_rate.into());
@@ -137,6 +188,8 @@ impl Tap {
         samples: Vec::new(),
         sequencer_backend,
       })),
+      clock: None,
+      crossfade: 0.0,
       done: 0.0,
       format: SoundFormat {
         channels: Self::CHANNELS,
@@ -145,10 +198,27 @@ impl Tap {
       last: None,
       muted,
       paused,
+      playback: None,
+      playlist: Vec::new(),
+      preloads: Vec::new(),
       sequencer,
       stream: None,
+      taps: Vec::new(),
       tempo: None,
       time: 0.0,
+      track: None,
+    }
+  }
+
+  pub(crate) fn next_track(&mut self) {
+    if let Some(index) = self.track {
+      self.play_track(index + 1);
+    }
+  }
+
+  pub(crate) fn nudge(&mut self, seconds: f64) {
+    if let Some(tempo) = &mut self.tempo {
+      tempo.offset += seconds;
     }
   }
 
@@ -160,7 +230,116 @@ impl Tap {
     self.paused.store(false, atomic::Ordering::Relaxed);
   }
 
-  fn position(&self) -> Option<Position> {
+  fn play_track(&mut self, mut index: usize) -> bool {
+    let sample_rate = self.format.sample_rate;
+
+    let mut fade_in = None;
+
+    loop {
+      let track = match self
+        .preloads
+        .iter()
+        .position(|preload| preload.index() == index)
+      {
+        Some(i) => self
+          .preloads
+          .swap_remove(i)
+          .join(&self.playlist, sample_rate),
+        None => Preload::load(&self.playlist, index, sample_rate),
+      };
+
+      let Some((loaded, wave, tempo)) = track else {
+        return false;
+      };
+
+      index = loaded;
+
+      let fade_in = *fade_in.get_or_insert_with(|| match (self.track.take(), &self.playback) {
+        (Some(_), Some(playback)) => {
+          self
+            .sequencer
+            .edit_relative(playback.id, self.crossfade, self.crossfade);
+          self.crossfade
+        }
+        _ => 0.0,
+      });
+
+      let fade = self.crossfade.min(wave.duration() / 2.0);
+
+      if self.sequence_wave(&wave, fade_in.min(fade), fade).is_none() {
+        index += 1;
+        continue;
+      }
+
+      log::info!("playing {}", self.playlist[index]);
+
+      self.track = Some(index);
+
+      self.tempo = tempo.map(|tempo| Tempo {
+        bpm: tempo.bpm,
+        offset: self.time + tempo.offset,
+      });
+
+      self.preload(index);
+
+      return true;
+    }
+  }
+
+  fn play_wave(
+    &mut self,
+    wave: &Arc<Wave>,
+    samples: Range<usize>,
+    looping: bool,
+    fade_in: f64,
+    fade_out: f64,
+  ) -> Option<EventId> {
+    if wave.channels() == 0 {
+      return None;
+    }
+
+    let duration = if looping {
+      f64::INFINITY
+    } else {
+      samples.len() as f64 / wave.sample_rate()
+    };
+
+    let loop_point = looping.then_some(samples.start);
+
+    let player = |channel| {
+      An(WavePlayer::new(
+        wave,
+        channel,
+        samples.start,
+        samples.end,
+        loop_point,
+      ))
+    };
+
+    let fade_in = fade_in.min(duration);
+    let fade_out = fade_out.min(duration);
+
+    let id = if wave.channels() == 1 {
+      self.sequence(player(0), duration, fade_in, fade_out)
+    } else {
+      self.sequence(player(0) | player(1), duration, fade_in, fade_out)
+    };
+
+    self.playback = Some(Playback {
+      id,
+      looping: looping.then(|| samples.clone()),
+      start: self.time - samples.start as f64 / wave.sample_rate(),
+      wave: wave.clone(),
+    });
+
+    Some(id)
+  }
+
+  pub(crate) fn position(&self) -> Option<Position> {
+    if let Some(clock) = &self.clock {
+      return clock.position();
+    }
+
     let tempo = self.tempo?;
 
     if self.time < tempo.offset {
@@ -173,7 +352,110 @@ impl Tap {
     Some(Position::from_quarter(quarter))
   }
 
-  pub(crate) fn sequence<T>(&mut self, node: An<T>, duration: f64, fade_in: f64, fade_out: f64)
+  fn preload(&mut self, index: usize) {
+    let neighbors = [
+      index.checked_sub(1),
+      Some(index + 1).filter(|next| *next < self.playlist.len()),
+    ];
+
+    self
+      .preloads
+      .retain(|preload| neighbors.contains(&Some(preload.index())));
+
+    for neighbor in neighbors.into_iter().flatten() {
+      if self
+        .preloads
+        .iter()
+        .any(|preload| preload.index() == neighbor)
+      {
+        continue;
+      }
+
+      match Preload::new(self.playlist.clone(), neighbor, self.format.sample_rate) {
+        Ok(preload) => self.preloads.push(preload),
+        Err(err) => log::warn!("failed to preload track {neighbor}: {err}"),
+      }
+    }
+  }
+
+  pub(crate) fn previous_track(&mut self) {
+    if let Some(index) = self.track {
+      self.play_track(index.saturating_sub(1));
+    }
+  }
+
+  pub(crate) fn queue(&mut self, playlist: Playlist, crossfade: f64) {
+    self.crossfade = crossfade;
+    self.playlist = playlist.tracks;
+    self.preloads.clear();
+    self.play_track(0);
+  }
+
+  pub(crate) fn scale_tempo(&mut self, factor: f64) {
+    if let Some(tempo) = &mut self.tempo {
+      tempo.bpm *= factor;
+    }
+  }
+
+  pub(crate) fn seek(&mut self, start: Position, end: Option<Position>) -> Result {
+    ensure!(self.clock.is_none(), error::SeekClock);
+
+    let tempo = self.tempo.context(error::SeekTempo)?;
+
+    let playback = self.playback.as_ref().context(error::SeekSource)?;
+
+    let index = |position: Position| {
+      let seconds = tempo.time(position.quarter() as f64 / 4.0) - playback.start;
+      #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+      let index = (seconds * playback.wave.sample_rate()).round() as usize;
+      (seconds >= 0.0 && index < playback.wave.len()).then_some(index)
+    };
+
+    let from = index(start).context(error::SeekRange { position: start })?;
+
+    let to = if let Some(end) = end {
+      let to = index(end).context(error::SeekRange { position: end })?;
+      ensure!(to > from, error::SeekLoop { end, start });
+      to
+    } else {
+      playback.wave.len()
+    };
+
+    let id = playback.id;
+    let previous = playback.start;
+    let wave = playback.wave.clone();
+
+    self
+      .sequencer
+      .edit_relative(id, Self::SEEK_FADE, Self::SEEK_FADE);
+
+    self.play_wave(&wave, from..to, end.is_some(), Self::SEEK_FADE, 0.0);
+
+    let playback = self.playback.as_ref().unwrap();
+
+    self.done = if end.is_some() {
+      f64::INFINITY
+    } else {
+      playback.end()
+    };
+
+    self.tempo = Some(Tempo {
+      offset: tempo.offset + playback.start - previous,
+      ..tempo
+    });
+
+    self.last = None;
+
+    Ok(())
+  }
+
+  pub(crate) fn sequence<T>(
+    &mut self,
+    node: An<T>,
+    duration: f64,
+    fade_in: f64,
+    fade_out: f64,
+  ) -> EventId
   where
     T: AudioNode<Inputs = U0> + IntoStereo<T::Outputs> + 'static,
   {
@@ -185,7 +467,7 @@ impl Tap {
       fade_in,
       fade_out,
       node.0.into_stereo(),
-    );
+    )
   }
 
   pub(crate) fn sequence_track(&mut self, track: &Track, fade_in: f64, fade_out: f64) {
@@ -196,21 +478,13 @@ impl Tap {
     });
   }
 
-  pub(crate) fn sequence_wave(&mut self, wave: &Arc<Wave>, fade_in: f64, fade_out: f64) {
-    if wave.channels() == 0 {
-      return;
-    }
-
-    let duration = wave.duration();
-
-    if wave.channels() == 1 {
-      let mono = WavePlayer::new(wave, 0, 0, wave.len(), None);
-      self.sequence(An(mono), duration, fade_in, fade_out);
-    } else {
-      let left = WavePlayer::new(wave, 0, 0, wave.len(), None);
-      let right = WavePlayer::new(wave, 1, 0, wave.len(), None);
-      self.sequence(An(left) | An(right), duration, fade_in, fade_out);
-    }
+  pub(crate) fn sequence_wave(
+    &mut self,
+    wave: &Arc<Wave>,
+    fade_in: f64,
+    fade_out: f64,
+  ) -> Option<EventId> {
+    self.play_wave(wave, 0..wave.len(), false, fade_in, fade_out)
   }
 
   pub(crate) fn stream(
@@ -243,7 +517,67 @@ impl Tap {
     Ok(())
   }
 
+  pub(crate) fn sync(&mut self, clock: Option<Clock>) {
+    self.clock = clock.filter(Clock::active);
+  }
+
+  pub(crate) fn tap_tempo(&mut self) {
+    if self
+      .taps
+      .last()
+      .is_some_and(|last| self.time - last > Self::TAP_TIMEOUT)
+    {
+      self.taps.clear();
+    }
+
+    self.taps.push(self.time);
+
+    let beats = self
+      .taps
+      .iter()
+      .enumerate()
+      .map(|(i, &time)| (i as f64, time))
+      .collect::<Vec<(f64, f64)>>();
+
+    if let Some(tempo) = Tempo::fit(&beats) {
+      self.tempo = Some(tempo);
+    }
+  }
+
+  pub(crate) fn tempo(&self) -> Option<Tempo> {
+    if let Some(clock) = &self.clock {
+      return clock.tempo(self.time);
+    }
+
+    self.tempo
+  }
+
   pub(crate) fn tick(&mut self, dt: Duration) -> Tick {
+    if let Some(index) = self.track
+      && let Some(playback) = &self.playback
+      && playback.looping.is_none()
+      && self.time >= playback.end() - self.crossfade.min(playback.wave.duration() / 2.0)
+      && !self.play_track(index + 1)
+    {
+      self.track = None;
+    }
+
+    if let Some(playback) = &mut self.playback
+      && let Some(looping) = &playback.looping
+    {
+      let sample_rate = playback.wave.sample_rate();
+
+      if self.time >= playback.start + looping.end as f64 / sample_rate {
+        let length = looping.len() as f64 / sample_rate;
+
+        playback.start += length;
+
+        if let Some(tempo) = &mut self.tempo {
+          tempo.offset += length;
+        }
+      }
+    }
+
     let last = self.last;
     let position = self.position();
     self.last = position;
@@ -251,11 +585,15 @@ impl Tap {
       dt,
       last,
       position,
-      tempo: self.tempo,
+      tempo: self.tempo(),
       time: self.time,
     }
   }
 
+  pub(crate) fn time(&self) -> f64 {
+    self.time
+  }
+
   pub(crate) fn toggle_muted(&self) {
     self.muted.fetch_xor(true, atomic::Ordering::Relaxed);
   }
@@ -312,3 +650,207 @@ impl Backend {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    assert_float_eq::assert_float_absolute_eq,
+    fundsp::prelude::{pan, sine_hz},
+  };
+
+  #[test]
+  fn clock() {
+    let mut tap = Tap::new(&Options::default(), 48_000);
+
+    tap.tempo = Some(Tempo {
+      bpm: 90.0,
+      offset: 0.0,
+    });
+
+    tap.elapse(Duration::from_secs(2));
+
+    let mut clock = Clock::default();
+
+    for i in 0..48 {
+      clock.update(i * 20_833, Transport::Clock);
+    }
+
+    tap.sync(Some(clock.clone()));
+    assert_eq!(tap.position(), Some(Position::from_beat(3)));
+    assert_float_absolute_eq!(tap.tempo().unwrap().bpm, 90.0);
+
+    clock.update(48 * 20_833, Transport::Start);
+
+    tap.sync(Some(clock));
+    assert_eq!(tap.position(), Some(Position::from_quarter(0)));
+    assert_float_absolute_eq!(tap.tempo().unwrap().bpm, 120.0, 0.01);
+
+    assert_eq!(
+      tap.seek(position::bar(1), None).unwrap_err().to_string(),
+      "cannot seek while synced to MIDI clock",
+    );
+  }
+
+  #[test]
+  fn playlist() {
+    let (_tempdir, tempdir) = tempdir().unwrap();
+
+    let mut tracks = Vec::new();
+
+    for name in ["a.wav", "b.wav", "c.wav"] {
+      let path = tempdir.join(name);
+      Wave::render(48_000.0, 1.0, &mut (sine_hz::<f32>(440.0) >> pan(0.0)))
+        .save_wav16(&path)
+        .unwrap();
+      tracks.push(path);
+    }
+
+    fs::write(
+      tempdir.join("a.wav.tempo.yaml"),
+      serde_yaml::to_string(&Tempo {
+        bpm: 120.0,
+        offset: 0.0,
+      })
+      .unwrap(),
+    )
+    .unwrap();
+
+    let mut tap = Tap::new(&Options::default(), 48_000);
+
+    assert_eq!(
+      Options {
+        crossfade: Some(-1.0),
+        playlist: Some(tempdir.join("playlist.yaml")),
+        ..default()
+      }
+      .add_source(&Config::default(), &mut tap)
+      .unwrap_err()
+      .to_string(),
+      "crossfade -1s is negative",
+    );
+
+    tap.queue(Playlist { tracks }, 0.25);
+
+    let index = |tap: &Tap| tap.track;
+
+    assert_eq!(index(&tap), Some(0));
+    assert_float_absolute_eq!(tap.tempo().unwrap().bpm, 120.0);
+
+    let mut samples = vec![0.0; 4_800];
+
+    while tap.time() < 0.7 {
+      tap.write(&mut samples);
+      tap.drain();
+      tap.tick(Duration::ZERO);
+    }
+
+    assert_eq!(index(&tap), Some(0));
+
+    while tap.time() < 0.8 {
+      tap.write(&mut samples);
+      tap.drain();
+      tap.tick(Duration::ZERO);
+    }
+
+    assert_eq!(index(&tap), Some(1));
+    assert!(tap.tempo().is_none());
+
+    tap.next_track();
+    assert_eq!(index(&tap), Some(2));
+    assert!(tap.preloads.iter().any(|preload| preload.index() == 1));
+
+    tap.previous_track();
+    assert_eq!(index(&tap), Some(1));
+    assert!(tap.tempo().is_none());
+
+    tap.next_track();
+    tap.next_track();
+    assert_eq!(index(&tap), Some(2));
+
+    tap.previous_track();
+    assert_eq!(index(&tap), Some(1));
+  }
+
+  #[test]
+  fn seek() {
+    fn advance(tap: &mut Tap, seconds: f64) {
+      let mut samples = vec![0.0; 4_800];
+      let end = tap.time() + seconds;
+      while tap.time() < end {
+        tap.write(&mut samples);
+        tap.drain();
+        tap.tick(Duration::ZERO);
+      }
+    }
+
+    let mut tap = Tap::new(&Options::default(), 48_000);
+
+    assert!(tap.seek(position::bar(1), None).is_err());
+
+    tap.sequence_track(
+      &Track {
+        tempo: Tempo {
+          bpm: 120.0,
+          offset: 0.0,
+        },
+        wave: Arc::new(Wave::render(
+          48_000.0,
+          8.0,
+          &mut (sine_hz::<f32>(440.0) >> pan(0.0)),
+        )),
+      },
+      0.0,
+      0.0,
+    );
+
+    advance(&mut tap, 0.5);
+
+    tap.seek(position::bar(3), None).unwrap();
+    assert_eq!(tap.position(), Some(position::bar(3)));
+    assert_float_absolute_eq!(tap.playback.as_ref().unwrap().start, tap.time() - 4.0);
+    assert!(!tap.is_done());
+
+    assert!(tap.seek(position::bar(5), None).is_err());
+    assert!(tap.seek(position::bar(3), Some(position::bar(2))).is_err());
+
+    tap.seek(position::bar(2), Some(position::bar(3))).unwrap();
+    assert_eq!(tap.position(), Some(position::bar(2)));
+
+    advance(&mut tap, 3.0);
+    assert_eq!(tap.position(), Some(position::bbq(2, 3, 1)));
+
+    tap.seek(position::bar(4), None).unwrap();
+    advance(&mut tap, 2.0);
+    assert!(tap.is_done());
+  }
+
+  #[test]
+  fn tap_tempo() {
+    let mut tap = Tap::new(&Options::default(), 48_000);
+
+    tap.elapse(Duration::from_secs_f64(1.0));
+
+    for _ in 0..4 {
+      tap.tap_tempo();
+      tap.elapse(Duration::from_secs_f64(0.5));
+    }
+
+    let tempo = tap.tempo().unwrap();
+    assert_float_absolute_eq!(tempo.bpm, 120.0);
+    assert_float_absolute_eq!(tempo.offset, 1.0);
+    assert_eq!(tap.position(), Some(Position::from_beat(4)));
+
+    tap.nudge(0.25);
+    tap.scale_tempo(2.0);
+
+    let tempo = tap.tempo().unwrap();
+    assert_float_absolute_eq!(tempo.bpm, 240.0);
+    assert_float_absolute_eq!(tempo.offset, 1.25);
+
+    tap.elapse(Duration::from_secs_f64(10.0));
+    tap.tap_tempo();
+
+    assert_float_absolute_eq!(tap.tempo().unwrap().bpm, 240.0);
+  }
+}
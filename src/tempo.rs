@@ -1,14 +1,19 @@
-use {super::*, std::process::Command};
+use super::*;
 
-static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+\.\d+) bpm\n$").unwrap());
-
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
 pub(crate) struct Tempo {
   pub(crate) bpm: f64,
   pub(crate) offset: f64,
 }
 
 impl Tempo {
+  const FRAME: usize = 2048;
+  const HOP: usize = 512;
+  const MAX_BPM: f64 = 180.0;
+  const MIN_BPM: f64 = 60.0;
+  const MULTIPLES: u32 = 8;
+
   pub(crate) fn bars(self, time: f64) -> f64 {
     self.beats(time) / 4.0
   }
@@ -17,41 +22,110 @@ impl Tempo {
     (time - self.offset) / 60.0 * self.bpm
   }
 
-  pub(crate) fn detect(path: &Utf8Path) -> Result<Self> {
-    let bpm = {
-      let stdout = Command::new("aubio").arg("tempo").arg(path).stdout_utf8()?;
+  fn cached(path: &Utf8Path, cache: &Utf8Path) -> Option<Self> {
+    let modified = |path: &Utf8Path| fs::metadata(path).and_then(|metadata| metadata.modified());
 
-      let captures = RE
-        .captures(&stdout)
-        .context(error::TempoParse { stdout: &stdout })?;
+    if modified(cache).ok()? < modified(path).ok()? {
+      return None;
+    }
 
-      captures[1]
-        .parse::<f64>()
-        .ok()
-        .context(error::TempoParse { stdout: &stdout })?
-    };
+    let yaml = fs::read_to_string(cache).ok()?;
 
-    let offset = {
-      let stdout = Command::new("aubio").arg("beat").arg(path).stdout_utf8()?;
+    match serde_yaml::from_str(&yaml) {
+      Ok(tempo) => Some(tempo),
+      Err(err) => {
+        log::warn!("ignoring invalid tempo cache at {cache}: {err}");
+        None
+      }
+    }
+  }
 
-      let mut beats = Vec::new();
+  pub(crate) fn detect(wave: &Wave) -> Option<Self> {
+    let envelope = Self::envelope(wave);
 
-      for line in stdout.lines() {
-        let line = line.trim();
-        beats.push(
-          line
-            .parse::<f64>()
-            .ok()
-            .context(error::TempoParse { stdout: line })?,
-        );
-      }
+    let frame_rate = wave.sample_rate() / Self::HOP as f64;
 
-      Self::detect_offset(bpm, &beats)
+    let bpm = Self::detect_bpm(&envelope, frame_rate)?;
+
+    // flux peaks once an onset enters the trailing slope of the window
+    let beats = Self::detect_onsets(&envelope)
+      .into_iter()
+      .map(|frame| (frame * Self::HOP + Self::FRAME * 3 / 4) as f64 / wave.sample_rate())
+      .collect::<Vec<f64>>();
+
+    let mut tempo = Self {
+      bpm,
+      offset: Self::detect_offset(bpm, &beats),
     };
 
-    log::info!("loaded track with {bpm:.2} bpm at {offset:.2} offset");
+    for _ in 0..2 {
+      tempo = tempo.refine(&beats);
+    }
+
+    Some(tempo)
+  }
+
+  fn detect_bpm(envelope: &[f32], frame_rate: f64) -> Option<f64> {
+    fn autocorrelation(envelope: &[f32], lag: f64) -> f64 {
+      #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+      let whole = lag as usize;
+      #[allow(clippy::cast_possible_truncation)]
+      let fract = lag.fract() as f32;
+
+      envelope
+        .iter()
+        .zip(envelope.iter().skip(whole))
+        .zip(envelope.iter().skip(whole + 1))
+        .map(|((a, b), c)| f64::from(a * (b * (1.0 - fract) + c * fract)))
+        .sum()
+    }
+
+    fn score(envelope: &[f32], lag: f64) -> f64 {
+      (1..=Tempo::MULTIPLES)
+        .map(|multiple| autocorrelation(envelope, lag * f64::from(multiple)))
+        .sum()
+    }
+
+    let bpm = |lag: f64| 60.0 * frame_rate / lag;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let lags = (60.0 * frame_rate / Self::MAX_BPM).floor() as usize
+      ..=(60.0 * frame_rate / Self::MIN_BPM).ceil() as usize;
+
+    if envelope.len() <= lags.end() * (Self::MULTIPLES.into_usize() + 1) {
+      return None;
+    }
+
+    let smoothed = (0..envelope.len())
+      .map(|i| {
+        envelope[i.saturating_sub(2)..(i + 3).min(envelope.len())]
+          .iter()
+          .zip([0.25, 0.5, 1.0, 0.5, 0.25].iter().skip(2 - i.min(2)))
+          .map(|(value, weight)| value * weight)
+          .sum()
+      })
+      .collect::<Vec<f32>>();
 
-    Ok(Self { bpm, offset })
+    let envelope = smoothed.as_slice();
+
+    let (lag, best) = lags
+      .map(|lag| {
+        let lag = lag as f64;
+        let prior = (-0.5 * (bpm(lag) / 120.0).log2().powi(2)).exp();
+        (lag, score(envelope, lag) * prior)
+      })
+      .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+    if best <= 0.0 {
+      return None;
+    }
+
+    let (lag, _score) = (-100..=100)
+      .map(|step| lag + f64::from(step) / 100.0)
+      .map(|lag| (lag, score(envelope, lag)))
+      .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+    Some(bpm(lag))
   }
 
   fn detect_offset(bpm: f64, beats: &[f64]) -> f64 {
@@ -79,12 +153,221 @@ impl Tempo {
 
     best.map(|(offset, _error)| offset).unwrap()
   }
+
+  fn detect_onsets(envelope: &[f32]) -> Vec<usize> {
+    const RADIUS: usize = 3;
+
+    let mean = envelope.iter().sum::<f32>() / envelope.len().max(1) as f32;
+
+    (0..envelope.len())
+      .filter(|&i| {
+        let value = envelope[i];
+        value > mean
+          && envelope[i.saturating_sub(RADIUS)..(i + RADIUS + 1).min(envelope.len())]
+            .iter()
+            .all(|&neighbor| neighbor <= value)
+          && (i == 0 || envelope[i - 1] < value)
+      })
+      .collect()
+  }
+
+  fn envelope(wave: &Wave) -> Vec<f32> {
+    const RADIUS: usize = 8;
+
+    let channels = wave.channels();
+
+    let samples = (0..wave.len())
+      .map(|i| {
+        (0..channels)
+          .map(|channel| wave.at(channel, i))
+          .sum::<f32>()
+          / channels as f32
+      })
+      .collect::<Vec<f32>>();
+
+    let window = (0..Self::FRAME)
+      .map(|i| {
+        (std::f32::consts::PI * i as f32 / Self::FRAME as f32)
+          .sin()
+          .powi(2)
+      })
+      .collect::<Vec<f32>>();
+
+    let fft = FftPlanner::new().plan_fft_forward(Self::FRAME);
+
+    let mut scratch = vec![Complex::default(); fft.get_inplace_scratch_len()];
+
+    let mut buffer = Vec::with_capacity(Self::FRAME);
+
+    let mut previous = vec![0.0; Self::FRAME / 2];
+
+    let mut flux = Vec::new();
+
+    for start in (0..samples.len().saturating_sub(Self::FRAME)).step_by(Self::HOP) {
+      buffer.clear();
+      buffer.extend(
+        samples[start..start + Self::FRAME]
+          .iter()
+          .zip(&window)
+          .map(|(sample, weight)| Complex::from(sample * weight)),
+      );
+
+      fft.process_with_scratch(&mut buffer, &mut scratch);
+
+      let mut sum = 0.0;
+
+      for (previous, bin) in previous.iter_mut().zip(&buffer) {
+        let magnitude = (1.0 + 100.0 * bin.norm()).ln();
+        sum += (magnitude - *previous).max(0.0);
+        *previous = magnitude;
+      }
+
+      flux.push(sum);
+    }
+
+    if let Some(first) = flux.first_mut() {
+      *first = 0.0;
+    }
+
+    (0..flux.len())
+      .map(|i| {
+        let neighborhood = &flux[i.saturating_sub(RADIUS)..(i + RADIUS + 1).min(flux.len())];
+        let mean = neighborhood.iter().sum::<f32>() / neighborhood.len() as f32;
+        (flux[i] - mean).max(0.0)
+      })
+      .collect()
+  }
+
+  pub(crate) fn fit(beats: &[(f64, f64)]) -> Option<Self> {
+    if beats.len() < 2 {
+      return None;
+    }
+
+    let count = beats.len() as f64;
+
+    let mean_n = beats.iter().map(|(n, _time)| n).sum::<f64>() / count;
+    let mean_time = beats.iter().map(|(_n, time)| time).sum::<f64>() / count;
+
+    let covariance = beats
+      .iter()
+      .map(|(n, time)| (n - mean_n) * (time - mean_time))
+      .sum::<f64>();
+
+    let variance = beats
+      .iter()
+      .map(|(n, _time)| (n - mean_n).powi(2))
+      .sum::<f64>();
+
+    let period = covariance / variance;
+
+    if !period.is_normal() || period < 0.0 {
+      return None;
+    }
+
+    Some(Self {
+      bpm: 60.0 / period,
+      offset: mean_time - period * mean_n,
+    })
+  }
+
+  pub(crate) fn load(path: &Utf8Path, wave: &Wave) -> Result<Self> {
+    let cache = Utf8PathBuf::from(format!("{path}.tempo.yaml"));
+
+    if let Some(tempo) = Self::cached(path, &cache) {
+      log::info!("loaded cached tempo from {cache}");
+      return Ok(tempo);
+    }
+
+    let start = Instant::now();
+
+    let tempo = Self::detect(wave).context(error::TempoDetect { path })?;
+
+    log::info!(
+      "detected {:.2} bpm at {:.2} offset in {:.2}s",
+      tempo.bpm,
+      tempo.offset,
+      start.elapsed().as_secs_f32(),
+    );
+
+    match serde_yaml::to_string(&tempo) {
+      Ok(yaml) => {
+        if let Err(err) = fs::write(&cache, yaml) {
+          log::warn!("failed to write tempo cache to {cache}: {err}");
+        }
+      }
+      Err(err) => log::warn!("failed to serialize tempo: {err}"),
+    }
+
+    Ok(tempo)
+  }
+
+  fn refine(self, beats: &[f64]) -> Self {
+    let period = 60.0 / self.bpm;
+
+    let points = beats
+      .iter()
+      .filter_map(|&beat| {
+        let n = ((beat - self.offset) / period).round();
+        let residual = beat - self.offset - n * period;
+        (residual.abs() < period * 0.1).then_some((n, beat))
+      })
+      .collect::<Vec<(f64, f64)>>();
+
+    let Some(fit) = Self::fit(&points) else {
+      return self;
+    };
+
+    Self {
+      bpm: fit.bpm,
+      offset: fit.offset.rem_euclid(60.0 / fit.bpm),
+    }
+  }
+
+  pub(crate) fn time(self, beats: f64) -> f64 {
+    self.offset + beats * 60.0 / self.bpm
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use {super::*, assert_float_eq::assert_float_absolute_eq};
 
+  fn clicks(bpm: f64, offset: f64, duration: f64) -> Wave {
+    let sample_rate = 48_000.0;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let mut samples = vec![0.0; (duration * sample_rate) as usize];
+
+    let mut beat = offset;
+
+    while beat < duration {
+      #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+      let start = (beat * sample_rate) as usize;
+
+      for (i, sample) in samples.iter_mut().skip(start).take(480).enumerate() {
+        *sample = (i as f32 * 0.3).sin() * (1.0 - i as f32 / 480.0);
+      }
+
+      beat += 60.0 / bpm;
+    }
+
+    Wave::from_samples(sample_rate, &samples)
+  }
+
+  #[test]
+  fn detect() {
+    #[track_caller]
+    fn case(bpm: f64, offset: f64) {
+      let tempo = Tempo::detect(&clicks(bpm, offset, 30.0)).unwrap();
+      assert_float_absolute_eq!(tempo.bpm, bpm, 0.01);
+      assert_float_absolute_eq!(tempo.offset, offset, 0.005);
+    }
+
+    case(120.0, 0.25);
+    case(93.0, 0.1);
+    case(140.0, 0.3);
+  }
+
   #[test]
   fn detect_offset() {
     #[track_caller]
@@ -96,4 +379,48 @@ mod tests {
     case(60.0, &[0.5, 1.5, 2.5, 3.5], 0.5);
     case(60.0, &[0.5, 1.0, 1.5, 2.5, 3.5], 0.5);
   }
+
+  #[test]
+  fn detect_silence() {
+    assert!(Tempo::detect(&Wave::from_samples(48_000.0, &vec![0.0; 48_000 * 10])).is_none());
+  }
+
+  #[test]
+  fn fit() {
+    let tempo = Tempo::fit(&[(0.0, 1.0), (1.0, 1.49), (2.0, 2.01), (3.0, 2.5)]).unwrap();
+    assert_float_absolute_eq!(tempo.bpm, 120.0, 1.0);
+    assert_float_absolute_eq!(tempo.offset, 1.0, 0.01);
+
+    assert!(Tempo::fit(&[(0.0, 1.0)]).is_none());
+    assert!(Tempo::fit(&[(0.0, 1.0), (1.0, 1.0)]).is_none());
+  }
+
+  #[test]
+  fn load_caches_tempo() {
+    let (_tempdir, path) = tempdir().unwrap();
+
+    let path = path.join("clicks.wav");
+
+    let wave = clicks(120.0, 0.25, 30.0);
+
+    fs::write(&path, "").unwrap();
+
+    let tempo = Tempo::load(&path, &wave).unwrap();
+
+    let cache = Utf8PathBuf::from(format!("{path}.tempo.yaml"));
+
+    assert_eq!(
+      serde_yaml::from_str::<Tempo>(&fs::read_to_string(&cache).unwrap()).unwrap(),
+      tempo,
+    );
+
+    let cached = Tempo {
+      bpm: 100.0,
+      offset: 0.5,
+    };
+
+    fs::write(&cache, serde_yaml::to_string(&cached).unwrap()).unwrap();
+
+    assert_eq!(Tempo::load(&path, &wave).unwrap(), cached);
+  }
 }
@@ -0,0 +1,76 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Timestamp {
+  Position(Position),
+  Seconds(f64),
+}
+
+impl Timestamp {
+  pub(crate) fn seconds(self, tempo: Option<Tempo>) -> Result<f64> {
+    match self {
+      Self::Position(position) => {
+        let tempo = tempo.context(error::CapturePositionTempo { position })?;
+        Ok(tempo.time(position.quarter() as f64 / 4.0))
+      }
+      Self::Seconds(seconds) => Ok(seconds),
+    }
+  }
+}
+
+impl FromStr for Timestamp {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, String> {
+    if let Some(position) = Position::parse(s) {
+      return Ok(Self::Position(position));
+    }
+
+    match s.parse::<f64>() {
+      Ok(seconds) if seconds.is_finite() && seconds >= 0.0 => Ok(Self::Seconds(seconds)),
+      _ => Err(format!(
+        "invalid timestamp `{s}`: expected seconds or bar.beat.quarter"
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, assert_float_eq::assert_float_absolute_eq};
+
+  #[test]
+  fn from_str() {
+    assert_eq!(
+      "32.1.1".parse::<Timestamp>().unwrap(),
+      Timestamp::Position(position::bar(32)),
+    );
+    assert_eq!("1.5".parse::<Timestamp>().unwrap(), Timestamp::Seconds(1.5));
+    assert_eq!("0".parse::<Timestamp>().unwrap(), Timestamp::Seconds(0.0));
+    assert!("-1".parse::<Timestamp>().is_err());
+    assert!("inf".parse::<Timestamp>().is_err());
+    assert!("0.1.1".parse::<Timestamp>().is_err());
+  }
+
+  #[test]
+  fn seconds() {
+    let tempo = Tempo {
+      bpm: 120.0,
+      offset: 0.25,
+    };
+
+    let seconds = Timestamp::Position(position::bar(3))
+      .seconds(Some(tempo))
+      .unwrap();
+    assert_float_absolute_eq!(seconds, 4.25);
+
+    let seconds = Timestamp::Position(position::bbq(1, 2, 3))
+      .seconds(Some(tempo))
+      .unwrap();
+    assert_float_absolute_eq!(seconds, 0.25 + 0.5 + 0.25);
+
+    assert_float_absolute_eq!(Timestamp::Seconds(7.0).seconds(None).unwrap(), 7.0);
+
+    assert!(Timestamp::Position(position::bar(1)).seconds(None).is_err());
+  }
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub(crate) struct Transformation2 {
   pub(crate) period: Option<f32>,
   pub(crate) rotation: f32,
@@ -1,6 +1,7 @@
 use super::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub(crate) struct Transformation3 {
   pub(crate) period: Option<f32>,
   pub(crate) rotation: UnitQuaternion<f32>,
@@ -0,0 +1,49 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Transport {
+  Clock,
+  Continue,
+  SongPosition(u16),
+  Start,
+  Stop,
+}
+
+impl Transport {
+  pub(crate) fn parse(event: &[u8]) -> Option<Self> {
+    match midly::live::LiveEvent::parse(event).ok()? {
+      midly::live::LiveEvent::Common(midly::live::SystemCommon::SongPosition(position)) => {
+        Some(Self::SongPosition(position.into()))
+      }
+      midly::live::LiveEvent::Realtime(realtime) => match realtime {
+        midly::live::SystemRealtime::Continue => Some(Self::Continue),
+        midly::live::SystemRealtime::Start => Some(Self::Start),
+        midly::live::SystemRealtime::Stop => Some(Self::Stop),
+        midly::live::SystemRealtime::TimingClock => Some(Self::Clock),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    #[track_caller]
+    fn case(event: &[u8], expected: Option<Transport>) {
+      assert_eq!(Transport::parse(event), expected);
+    }
+
+    case(&[0xF8], Some(Transport::Clock));
+    case(&[0xFA], Some(Transport::Start));
+    case(&[0xFB], Some(Transport::Continue));
+    case(&[0xFC], Some(Transport::Stop));
+    case(&[0xF2, 0x10, 0x01], Some(Transport::SongPosition(144)));
+    case(&[0xFE], None);
+    case(&[0x90, 0x00, 0x7F], None);
+  }
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, IntoStaticStr, PartialEq, ValueEnum)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum VideoFormat {
+  Apng,
+  Ffv1,
+  Gif,
+  #[default]
+  H264,
+  Png,
+  Prores,
+}
+
+impl VideoFormat {
+  pub(crate) fn audio_codec(self) -> Option<&'static str> {
+    match self {
+      Self::Apng | Self::Gif | Self::Png => None,
+      Self::Ffv1 => Some("flac"),
+      Self::H264 => Some("aac"),
+      Self::Prores => Some("pcm_s24le"),
+    }
+  }
+
+  pub(crate) fn extension(self) -> Option<&'static str> {
+    match self {
+      Self::Apng => Some("apng"),
+      Self::Ffv1 => Some("mkv"),
+      Self::Gif => Some("gif"),
+      Self::H264 => Some("mp4"),
+      Self::Png => None,
+      Self::Prores => Some("mov"),
+    }
+  }
+
+  pub(crate) fn faststart(self) -> bool {
+    matches!(self, Self::H264 | Self::Prores)
+  }
+
+  fn name(self) -> &'static str {
+    self.into()
+  }
+
+  fn pixel_format(self) -> Option<&'static str> {
+    match self {
+      Self::Apng => Some("rgba"),
+      Self::Ffv1 => Some("bgra"),
+      Self::Gif | Self::Png => None,
+      Self::H264 => Some("yuv420p"),
+      Self::Prores => Some("yuva444p10le"),
+    }
+  }
+
+  pub(crate) fn video_arguments(
+    self,
+    encoders: &BTreeSet<String>,
+    realtime: bool,
+  ) -> Vec<[&'static str; 2]> {
+    let mut arguments = match self {
+      Self::Apng => vec![["-c:v", "apng"], ["-plays", "0"]],
+      Self::Ffv1 => vec![["-c:v", "ffv1"], ["-level", "3"]],
+      Self::Gif => vec![
+        [
+          "-filter_complex",
+          "[0:v]split[a][b];[a]palettegen[p];[b][p]paletteuse",
+        ],
+        ["-loop", "0"],
+      ],
+      Self::H264 => {
+        let mut arguments = if encoders.contains("h264_videotoolbox") {
+          vec![
+            ["-c:v", "h264_videotoolbox"],
+            ["-q:v", if realtime { "100" } else { "90" }],
+            ["-realtime", "true"],
+          ]
+        } else {
+          vec![["-c:v", "libx264"], ["-crf", "18"], ["-preset", "slow"]]
+        };
+        arguments.push(["-level:v", if realtime { "5.2" } else { "5.1" }]);
+        arguments
+      }
+      Self::Png => Vec::new(),
+      Self::Prores => vec![
+        ["-c:v", "prores_ks"],
+        ["-profile:v", "4444"],
+        ["-vendor", "apl0"],
+      ],
+    };
+
+    if matches!(self, Self::Ffv1 | Self::H264 | Self::Prores) {
+      arguments.extend([
+        ["-color_primaries", "bt709"],
+        ["-color_range", "pc"],
+        ["-color_trc", "bt709"],
+        ["-colorspace", "bt709"],
+      ]);
+    }
+
+    if let Some(pixel_format) = self.pixel_format() {
+      arguments.push(["-pix_fmt", pixel_format]);
+    }
+
+    arguments
+  }
+}
+
+impl Display for VideoFormat {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn video_arguments() {
+    #[track_caller]
+    fn case(format: VideoFormat, encoders: &[&str], expected: &[[&str; 2]]) {
+      let encoders = encoders.iter().map(|encoder| (*encoder).into()).collect();
+      assert_eq!(format.video_arguments(&encoders, false), expected);
+    }
+
+    case(
+      VideoFormat::H264,
+      &["libx264"],
+      &[
+        ["-c:v", "libx264"],
+        ["-crf", "18"],
+        ["-preset", "slow"],
+        ["-level:v", "5.1"],
+        ["-color_primaries", "bt709"],
+        ["-color_range", "pc"],
+        ["-color_trc", "bt709"],
+        ["-colorspace", "bt709"],
+        ["-pix_fmt", "yuv420p"],
+      ],
+    );
+
+    case(
+      VideoFormat::Ffv1,
+      &[],
+      &[
+        ["-c:v", "ffv1"],
+        ["-level", "3"],
+        ["-color_primaries", "bt709"],
+        ["-color_range", "pc"],
+        ["-color_trc", "bt709"],
+        ["-colorspace", "bt709"],
+        ["-pix_fmt", "bgra"],
+      ],
+    );
+
+    case(
+      VideoFormat::Apng,
+      &[],
+      &[["-c:v", "apng"], ["-plays", "0"], ["-pix_fmt", "rgba"]],
+    );
+
+    case(VideoFormat::Png, &[], &[]);
+  }
+}
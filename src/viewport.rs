@@ -1,6 +1,7 @@
 use super::*;
 
-#[derive(Clone, Copy, IntoStaticStr)]
+#[derive(Clone, Copy, Deserialize, IntoStaticStr, Serialize)]
+#[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub(crate) enum Viewport {
   Fill { position: Vec2f },
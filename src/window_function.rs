@@ -0,0 +1,29 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum WindowFunction {
+  Blackman,
+  #[default]
+  Hann,
+  Rectangular,
+}
+
+impl WindowFunction {
+  pub(crate) fn coefficient(self, i: usize, size: usize) -> f32 {
+    let phase = i as f32 / size as f32 * TAU;
+    match self {
+      Self::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+      Self::Hann => 0.5 - 0.5 * phase.cos(),
+      Self::Rectangular => 1.0,
+    }
+  }
+
+  pub(crate) fn gain(self) -> f32 {
+    match self {
+      Self::Blackman => 0.42,
+      Self::Hann => 0.5,
+      Self::Rectangular => 1.0,
+    }
+  }
+}
@@ -24,6 +24,22 @@ var non_filtering_sampler: sampler;
 @binding({{ binding.next() }})
 var samples: texture_1d<f32>;
 
+@group(0)
+@binding({{ binding.next() }})
+var spectrogram: texture_2d<f32>;
+
+@group(0)
+@binding({{ binding.next() }})
+var stereo_frequencies: texture_2d<f32>;
+
+@group(0)
+@binding({{ binding.next() }})
+var stereo_samples: texture_2d<f32>;
+
+@group(0)
+@binding({{ binding.next() }})
+var waveforms: texture_2d<f32>;
+
 @group(0)
 @binding({{ binding.next() }})
 var<uniform> uniforms: Uniforms;
@@ -57,6 +73,7 @@ struct Uniforms {
   gain: f32,
   grid: f32,
   grid_transform: mat2x3f,
+  history: f32,
   interpolate: u32,
   mirror: vec4f,
   parameter: f32,
@@ -98,6 +115,19 @@ fn field_frequencies(p: vec2f) -> bool {
   return level > (-p.y + 1) * 0.5;
 }
 
+fn field_goniometer(p: vec2f) -> bool {
+  let count = u32(uniforms.sample_range * f32(textureDimensions(stereo_samples).x));
+  let stride = max(count / 256, 1u);
+  var on = false;
+  for (var i = 0u; i < count; i += stride) {
+    let left = textureLoad(stereo_samples, vec2(i, 0u), 0).x;
+    let right = textureLoad(stereo_samples, vec2(i, 1u), 0).x;
+    let point = vec2(right - left, left + right) * inverseSqrt(2.0) * uniforms.gain;
+    on = on || distance(p, point) < 0.02 * coefficient();
+  }
+  return on;
+}
+
 fn field_left(p: vec2f) -> bool {
   return p.x + 1 < coefficient();
 }
@@ -116,10 +146,25 @@ fn field_samples(p: vec2f) -> bool {
   return level < p.y;
 }
 
+fn field_spectrogram(p: vec2f) -> bool {
+  let x = (p.x + 1) * 0.5 * uniforms.frequency_range;
+  let y = uniforms.history - (-p.y + 1) * 0.5;
+  let level = textureSample(spectrogram, non_filtering_sampler, vec2(x, y)).x * uniforms.gain;
+  return level > 0.5;
+}
+
 fn field_square(p: vec2f) -> bool {
   return max(abs(p.x), abs(p.y)) < 0.5 * coefficient();
 }
 
+fn field_stereo(p: vec2f) -> bool {
+  let x = abs(p.x) * uniforms.frequency_range;
+  let y = select(0.25, 0.75, p.x > 0);
+  let level = textureSample(stereo_frequencies, non_filtering_sampler, vec2(x, y)).x
+    * uniforms.gain;
+  return level > (-p.y + 1) * 0.5;
+}
+
 fn field_texture_sample(p: vec2f) -> vec4f {
   let uv = p * 0.5 + 0.5;
   return textureSample(field_texture_binding, filtering_clamp_to_border_sampler, uv);
@@ -139,6 +184,13 @@ fn field_triangle(p: vec2f) -> bool {
     && p.y < 0.25 * coefficient();
 }
 
+fn field_waveforms(p: vec2f) -> bool {
+  let x = (p.x + 1) * 0.5 * uniforms.sample_range;
+  let y = uniforms.history - (-p.y + 1) * 0.5;
+  let level = textureSample(waveforms, non_filtering_sampler, vec2(x, y)).x * uniforms.gain;
+  return abs(level) > 0.5;
+}
+
 fn field_x(p: vec2f) -> bool {
   return abs(abs(p.x) - abs(p.y)) < sqrt(2) * 0.25 * coefficient();
 }